@@ -69,6 +69,7 @@ enum LB130USMessage {
         SetLightStateArgs,
         oneshot::Sender<Result<SetLightStateResponse, CommunicationError>>,
     ),
+    ToggleLightState(oneshot::Sender<Result<SetLightStateResponse, CommunicationError>>),
 }
 
 #[tracing::instrument(skip(messages))]
@@ -153,6 +154,17 @@ async fn lb130us_actor(
                     }
                 }
 
+                let _ = callback.send(res);
+            }
+            LB130USMessage::ToggleLightState(callback) => {
+                let res = handle_toggle_light_state(writer, reader).await;
+
+                if let Err(communication_error) = &res {
+                    if should_try_reconnecting(communication_error) {
+                        connection_cell.take();
+                    }
+                }
+
                 let _ = callback.send(res);
             }
         }
@@ -231,6 +243,31 @@ async fn handle_set_light_state<AW: AsyncWrite + Unpin, AR: AsyncRead + Unpin>(
     send_request(writer, reader, &request).await
 }
 
+/// Reads and then sets the state without handling any other message in between,
+/// so nothing sent through the same handle can change the state in the meantime
+#[tracing::instrument(skip(writer, reader))]
+async fn handle_toggle_light_state<AW: AsyncWrite + Unpin, AR: AsyncRead + Unpin>(
+    writer: &mut AW,
+    reader: &mut AR,
+) -> Result<SetLightStateResponse, CommunicationError> {
+    let lb130us = handle_get_sysinfo(writer, reader).await?;
+
+    let to = match lb130us.sys_info.light_state {
+        LightState::On { .. } => SetLightTo::Off(SetLightOff { on_off: Off }),
+        LightState::Off { .. } => SetLightTo::LastOn(SetLightLastOn { on_off: On }),
+    };
+
+    handle_set_light_state(
+        writer,
+        reader,
+        SetLightStateArgs {
+            to,
+            transition: None,
+        },
+    )
+    .await
+}
+
 #[derive(Debug, Clone)]
 pub struct LB130USHandle {
     sender: mpsc::Sender<LB130USMessage>,
@@ -275,4 +312,16 @@ impl LB130USHandle {
             .map_err(|_| HandleError::Dead)?
             .context(CommunicationSnafu)
     }
+
+    pub async fn toggle_light_state(&self) -> Result<SetLightStateResponse, HandleError> {
+        let (sender, receiver) = oneshot::channel();
+        self.sender
+            .send(LB130USMessage::ToggleLightState(sender))
+            .await
+            .map_err(|_| HandleError::Dead)?;
+        receiver
+            .await
+            .map_err(|_| HandleError::Dead)?
+            .context(CommunicationSnafu)
+    }
 }
@@ -1,14 +1,18 @@
-use std::convert::Infallible;
-
 use palette::{encoding::Srgb, Hsv, IntoColor};
-use protocol::light::{GetState, Kelvin, SetState, TurnToColor, TurnToTemperature};
+use protocol::color::Gamut;
+use protocol::light::{
+    ApplySetting, Brightness, Capabilities, ColorSetting, GetSetting, GetState, Kelvin,
+    LightCapabilities, LightSetting, SetState, TemperatureRange, Toggle, TurnToBrightness,
+    TurnToColor, TurnToTemperature,
+};
 use snafu::{ResultExt, Snafu};
 
 use crate::{
     connection::{HandleError, LB130USHandle},
     messages::{
-        Angle, Hsb, LightState, Off, On, Percentage, SetLightHsv, SetLightLastOn, SetLightOff,
-        SetLightStateArgs, SetLightTo,
+        self, Color, Hsb, IsColor, IsDimmable, IsVariableColorTemp, KelvinWithBrightness,
+        LightState, NoColorTemp, Off, On, Percentage, SetLightBrightness, SetLightHsv,
+        SetLightKelvin, SetLightLastOn, SetLightOff, SetLightStateArgs, SetLightTo,
     },
 };
 
@@ -64,11 +68,62 @@ impl SetState for LB130USHandle {
     }
 }
 
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum ToggleError {
+    HandleError { source: HandleError },
+}
+
+impl Toggle for LB130USHandle {
+    type Error = ToggleError;
+
+    async fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.toggle_light_state()
+            .await
+            .context(toggle_error::HandleSnafu)?;
+
+        Ok(())
+    }
+}
+
+fn to_kasa_kelvin(temperature: Kelvin) -> messages::Kelvin {
+    messages::Kelvin::new_saturating(temperature.get())
+}
+
+/// The bulb's gamut isn't published, and it behaves closest to treating colors as sRGB
+const GAMUT: Gamut = Gamut::SRGB;
+
+fn to_kasa_hsb(color: protocol::light::Oklch) -> Hsb {
+    let hsv: Hsv<Srgb, f64> = GAMUT.map(color).into_color();
+    hsv.into_color()
+}
+
+fn to_kasa_percentage(brightness: Brightness) -> Percentage {
+    Percentage::new_saturating(brightness.get())
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum TurnToTemperatureError {
+    HandleError { source: HandleError },
+}
+
 impl TurnToTemperature for LB130USHandle {
-    type Error = Infallible; // TODO
+    type Error = TurnToTemperatureError;
 
     async fn turn_to_temperature(&mut self, temperature: Kelvin) -> Result<(), Self::Error> {
-        todo!()
+        self.set_light_state(SetLightStateArgs {
+            to: SetLightTo::Kelvin(SetLightKelvin {
+                on_off: On,
+                color_temp: to_kasa_kelvin(temperature),
+                brightness: None,
+            }),
+            transition: None,
+        })
+        .await
+        .context(turn_to_temperature_error::HandleSnafu)?;
+
+        Ok(())
     }
 }
 
@@ -82,11 +137,14 @@ impl TurnToColor for LB130USHandle {
     type Error = TurnToColorError;
 
     async fn turn_to_color(&mut self, color: protocol::light::Oklch) -> Result<(), Self::Error> {
-        let hsv: Hsv<Srgb, f64> = color.into_color();
-        let hsb = hsv.into_color();
+        let hsb = to_kasa_hsb(color);
 
         self.set_light_state(SetLightStateArgs {
-            to: SetLightTo::Hsv(SetLightHsv { on_off: On, hsb }),
+            to: SetLightTo::Hsv(SetLightHsv {
+                on_off: On,
+                color_temp: NoColorTemp,
+                hsb,
+            }),
             transition: None,
         })
         .await
@@ -95,3 +153,173 @@ impl TurnToColor for LB130USHandle {
         Ok(())
     }
 }
+
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum TurnToBrightnessError {
+    HandleError { source: HandleError },
+}
+
+impl TurnToBrightness for LB130USHandle {
+    type Error = TurnToBrightnessError;
+
+    async fn turn_to_brightness(&mut self, brightness: Brightness) -> Result<(), Self::Error> {
+        self.set_light_state(SetLightStateArgs {
+            to: SetLightTo::Brightness(SetLightBrightness {
+                on_off: On,
+                brightness: to_kasa_percentage(brightness),
+            }),
+            transition: None,
+        })
+        .await
+        .context(turn_to_brightness_error::HandleSnafu)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum ApplySettingError {
+    HandleError { source: HandleError },
+}
+
+impl ApplySetting for LB130USHandle {
+    type Error = ApplySettingError;
+
+    /// Applied in a single message, so the bulb transitions everything at once
+    async fn apply_setting(&mut self, setting: LightSetting) -> Result<(), Self::Error> {
+        let brightness = setting.brightness.map(to_kasa_percentage);
+
+        let to = match (setting.state, setting.color) {
+            (protocol::light::State::Off, _) => SetLightTo::Off(SetLightOff { on_off: Off }),
+            (protocol::light::State::On, Some(ColorSetting::Color(color))) => {
+                let mut hsb = to_kasa_hsb(color);
+                if let Some(brightness) = brightness {
+                    hsb.brightness = brightness;
+                }
+
+                SetLightTo::Hsv(SetLightHsv {
+                    on_off: On,
+                    color_temp: NoColorTemp,
+                    hsb,
+                })
+            }
+            (protocol::light::State::On, Some(ColorSetting::Temperature(temperature))) => {
+                SetLightTo::Kelvin(SetLightKelvin {
+                    on_off: On,
+                    color_temp: to_kasa_kelvin(temperature),
+                    brightness,
+                })
+            }
+            (protocol::light::State::On, None) => match brightness {
+                Some(brightness) => SetLightTo::Brightness(SetLightBrightness {
+                    on_off: On,
+                    brightness,
+                }),
+                None => SetLightTo::LastOn(SetLightLastOn { on_off: On }),
+            },
+        };
+
+        self.set_light_state(SetLightStateArgs {
+            to,
+            transition: setting.transition,
+        })
+        .await
+        .context(apply_setting_error::HandleSnafu)?;
+
+        Ok(())
+    }
+}
+
+/// The hue and saturation as a color at full brightness, since the bulb keeps brightness separately
+fn from_kasa_color(color: Color) -> (ColorSetting, Brightness) {
+    match color {
+        Color::HSB(Hsb {
+            hue,
+            saturation,
+            brightness,
+        }) => {
+            let hsv: Hsv<Srgb, f64> = Hsv::new(
+                f64::from(hue.get()),
+                f64::from(saturation.get()) / f64::from(Percentage::MAX.get()),
+                1.0,
+            );
+
+            (
+                ColorSetting::Color(hsv.into_color()),
+                Brightness::new_saturating(brightness.get()),
+            )
+        }
+        Color::KelvinWithBrightness(KelvinWithBrightness { kelvin, brightness }) => (
+            ColorSetting::Temperature(Kelvin::new_saturating(kelvin.get())),
+            Brightness::new_saturating(brightness.get()),
+        ),
+    }
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum GetSettingError {
+    HandleError { source: HandleError },
+}
+
+impl GetSetting for LB130USHandle {
+    type Error = GetSettingError;
+
+    /// When the bulb is off, this is the setting it will come back on with
+    async fn get_setting(&self) -> Result<LightSetting, Self::Error> {
+        let sys = self
+            .get_sysinfo()
+            .await
+            .context(get_setting_error::HandleSnafu)?;
+
+        let (state, color) = match sys.sys_info.light_state {
+            LightState::On { color, .. } => (protocol::light::State::On, color),
+            LightState::Off { dft_on_state, .. } => {
+                (protocol::light::State::Off, dft_on_state.color)
+            }
+        };
+        let (color, brightness) = from_kasa_color(color);
+
+        Ok(LightSetting {
+            state,
+            color: Some(color),
+            brightness: Some(brightness),
+            transition: None,
+        })
+    }
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum CapabilitiesError {
+    HandleError { source: HandleError },
+}
+
+impl Capabilities for LB130USHandle {
+    type Error = CapabilitiesError;
+
+    async fn capabilities(&self) -> Result<LightCapabilities, Self::Error> {
+        let sys = self
+            .get_sysinfo()
+            .await
+            .context(capabilities_error::HandleSnafu)?;
+
+        let dimmable = matches!(sys.sys_info.is_dimmable, IsDimmable::Dimmable);
+        let color = matches!(sys.sys_info.is_color, IsColor::Color);
+        let temperature = match sys.sys_info.is_variable_color_temp {
+            IsVariableColorTemp::VariableColorTemp => Some(TemperatureRange {
+                min: Kelvin::new_saturating(messages::Kelvin::MIN.get()),
+                max: Kelvin::new_saturating(messages::Kelvin::MAX.get()),
+            }),
+            IsVariableColorTemp::NoVariableColorTemp => None,
+        };
+
+        Ok(LightCapabilities {
+            dimmable,
+            color,
+            temperature,
+        })
+    }
+}
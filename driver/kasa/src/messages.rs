@@ -5,7 +5,7 @@ use mac_address::{MacAddress, MacParseError};
 use palette::{FromColor, Hsv};
 use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize};
 use serde_repr::Deserialize_repr;
-use serde_with::{DeserializeFromStr, SerializeDisplay};
+use serde_with::{serde_as, DeserializeFromStr, DurationMilliSeconds, SerializeDisplay};
 
 #[derive(Debug)]
 pub struct GetSysInfo;
@@ -144,21 +144,21 @@ struct HardwareId(pub String);
 
 #[derive(Debug, Deserialize_repr)]
 #[repr(u8)]
-enum IsColor {
+pub enum IsColor {
     NoColor = 0,
     Color = 1,
 }
 
 #[derive(Debug, Deserialize_repr)]
 #[repr(u8)]
-enum IsDimmable {
+pub enum IsDimmable {
     NotDimmable = 0,
     Dimmable = 1,
 }
 
 #[derive(Debug, Deserialize_repr)]
 #[repr(u8)]
-enum IsVariableColorTemp {
+pub enum IsVariableColorTemp {
     NoVariableColorTemp = 0,
     VariableColorTemp = 1,
 }
@@ -201,23 +201,23 @@ struct RawColor {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hsb {
-    hue: Angle,
-    saturation: Percentage,
-    brightness: Percentage,
+    pub hue: Angle,
+    pub saturation: Percentage,
+    pub brightness: Percentage,
 }
 
 impl<S> FromColor<Hsv<S, f64>> for Hsb {
     fn from_color(hsv: Hsv<S, f64>) -> Self {
         let (hue, saturation, value) = hsv.into_components();
 
-        let hue = hue.into_positive_degrees();
-        let hue = Angle::new_saturating(hue as u16);
+        let hue = hue.into_positive_degrees().round() as u16 % 360;
+        let hue = Angle::new_saturating(hue);
 
         let saturation = saturation * (Percentage::MAX.get() as f64);
-        let saturation = Percentage::new_saturating(saturation as u8);
+        let saturation = Percentage::new_saturating(saturation.round() as u8);
 
         let brightness = value * (Percentage::MAX.get() as f64);
-        let brightness = Percentage::new_saturating(brightness as u8);
+        let brightness = Percentage::new_saturating(brightness.round() as u8);
 
         Hsb {
             hue,
@@ -228,13 +228,13 @@ impl<S> FromColor<Hsv<S, f64>> for Hsb {
 }
 
 #[derive(Debug, Clone)]
-struct KelvinWithBrightness {
-    kelvin: Kelvin,
-    brightness: Percentage,
+pub struct KelvinWithBrightness {
+    pub kelvin: Kelvin,
+    pub brightness: Percentage,
 }
 
 #[derive(Debug, Clone)]
-enum Color {
+pub enum Color {
     HSB(Hsb),
     KelvinWithBrightness(KelvinWithBrightness),
 }
@@ -297,6 +297,19 @@ impl Serialize for Off {
     }
 }
 
+/// Leaves color temperature mode, which the bulb needs in order to show a hue
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NoColorTemp;
+
+impl Serialize for NoColorTemp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u16(0)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct On;
 
@@ -343,14 +356,14 @@ pub enum LightState {
 }
 
 #[derive(Debug, Clone, Deserialize)]
-struct DftOnState {
+pub struct DftOnState {
     #[serde(flatten)]
-    color: Color,
-    mode: LightStateMode,
+    pub color: Color,
+    pub mode: LightStateMode,
 }
 
 #[derive(Debug, Clone, Deserialize)]
-enum LightStateMode {
+pub enum LightStateMode {
     #[serde(rename = "normal")]
     Normal,
 }
@@ -364,10 +377,13 @@ enum MicType {
 #[derive(Debug, Clone, Deserialize)]
 struct OemId(pub String);
 
+#[serde_as]
 #[derive(Debug, Clone, Serialize)]
 pub struct SetLightStateArgs {
     #[serde(flatten)]
     pub to: SetLightTo,
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(rename = "transition_period", skip_serializing_if = "Option::is_none")]
     pub transition: Option<Duration>,
 }
 
@@ -384,17 +400,34 @@ pub struct SetLightLastOn {
 #[derive(Debug, Clone, Serialize)]
 pub struct SetLightHsv {
     pub on_off: On,
+    pub color_temp: NoColorTemp,
     #[serde(flatten)]
     pub hsb: Hsb,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct SetLightKelvin {
+    pub on_off: On,
+    pub color_temp: Kelvin,
+    /// `None` to keep the current brightness
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brightness: Option<Percentage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SetLightBrightness {
+    pub on_off: On,
+    pub brightness: Percentage,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum SetLightTo {
     Off(SetLightOff),
     LastOn(SetLightLastOn),
     Hsv(SetLightHsv),
-    // TODO: kelvin
+    Kelvin(SetLightKelvin),
+    Brightness(SetLightBrightness),
 }
 
 #[derive(Debug, Clone, derive_more::From)]
@@ -98,6 +98,7 @@ async fn real_main(
     // let lamp = HomeAssistantLight {
     //     home_assistant,
     //     object_id: ObjectId::from_str("jacob_s_lamp_side").unwrap(),
+    //     gamut: Gamut::SRGB,
     // };
 
     let ip = [10, 0, 3, 71];
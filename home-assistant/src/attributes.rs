@@ -0,0 +1,43 @@
+use arbitrary_value::{arbitrary::Arbitrary, map::Map, map_key::MapKey};
+use pyo3::{prelude::*, types::PyDict};
+
+/// Extract an attribute that an entity may or may not have. Missing and `None` are treated the same
+pub(crate) fn item<'py, T: FromPyObject<'py>>(
+    dict: &Bound<'py, PyDict>,
+    key: &str,
+) -> PyResult<Option<T>> {
+    match dict.get_item(key)? {
+        Some(value) if !value.is_none() => value.extract().map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Extract an attribute that integrations disagree on the type of (like a number or a string) by always making it a string
+pub(crate) fn item_as_string(dict: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<String>> {
+    match dict.get_item(key)? {
+        Some(value) if !value.is_none() => Ok(Some(value.str()?.extract()?)),
+        _ => Ok(None),
+    }
+}
+
+/// Collect every attribute that isn't one of the `known` ones
+pub(crate) fn extra(dict: &Bound<'_, PyDict>, known: &[&str]) -> PyResult<Map> {
+    let mut extra = Map::default();
+
+    for (key, value) in dict.iter() {
+        let key = key.extract::<MapKey>()?;
+        if let MapKey::String(s) = &key {
+            if known.contains(&s.as_str()) {
+                continue;
+            }
+        }
+
+        // Integrations can put anything in their attributes,
+        // and one odd value shouldn't make the whole entity unreadable
+        if let Ok(value) = value.extract::<Arbitrary>() {
+            extra.0.insert(key, value);
+        }
+    }
+
+    Ok(extra)
+}
@@ -1,6 +1,6 @@
 use strum::EnumString;
 
-#[derive(Debug, Clone, EnumString, strum::Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumString, strum::Display)]
 #[strum(serialize_all = "snake_case")]
 pub enum Domain {
     Automation,
@@ -11,11 +11,14 @@ pub enum Domain {
     Conversation,
     Cover,
     DeviceTracker,
+    Fan,
     Group,
+    InputBoolean,
     InputDatetime,
     InputNumber,
     InputSelect,
     InputText,
+    LawnMower,
     Light,
     Lock,
     MediaPlayer,
@@ -29,6 +32,7 @@ pub enum Domain {
     Switch,
     Tag,
     Update,
+    Vacuum,
     Weather,
     Zone,
 }
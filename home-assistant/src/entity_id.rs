@@ -8,7 +8,7 @@ use super::{
     object_id::{ObjectId, ObjectIdParsingError},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EntityId(pub Domain, pub ObjectId);
 
 #[derive(Debug, Clone, Snafu)]
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use once_cell::sync::OnceCell;
 use pyo3::{exceptions::PyValueError, prelude::*};
 
 #[derive(Debug, Clone, strum::EnumString, strum::Display)]
@@ -19,3 +20,23 @@ impl<'py> FromPyObject<'py> for EventOrigin {
         Ok(event_origin)
     }
 }
+
+impl<'py> IntoPyObject<'py> for EventOrigin {
+    type Target = PyAny;
+
+    type Output = Bound<'py, Self::Target>;
+
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        static HOMEASSISTANT_CORE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+        let homeassistant_core = HOMEASSISTANT_CORE
+            .get_or_try_init(|| Result::<_, PyErr>::Ok(py.import("homeassistant.core")?.unbind()))?
+            .bind(py);
+
+        let event_origin_class = homeassistant_core.getattr("EventOrigin")?;
+
+        event_origin_class.call1((self.to_string(),))
+    }
+}
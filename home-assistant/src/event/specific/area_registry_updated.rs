@@ -0,0 +1,16 @@
+use pyo3::prelude::*;
+
+use crate::registry::{area::AreaId, Action};
+
+event_type!("area_registry_updated");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    pub action: Action,
+    /// `None` when the areas were reordered
+    pub area_id: Option<AreaId>,
+}
+
+/// An area registry updated event is fired when areas are created, updated, removed or reordered.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
@@ -0,0 +1,18 @@
+use pyo3::prelude::*;
+
+use crate::entity_id::EntityId;
+
+event_type!("automation_triggered");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    pub name: String,
+    pub entity_id: EntityId,
+    /// What triggered the automation, described for people
+    #[pyo3(default)]
+    pub source: Option<String>,
+}
+
+/// An automation triggered event is fired when an automation's trigger fires and its conditions pass.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
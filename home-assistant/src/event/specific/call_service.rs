@@ -0,0 +1,16 @@
+use pyo3::prelude::*;
+
+use crate::service::{service_domain::ServiceDomain, service_id::ServiceId};
+
+event_type!("call_service");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data<ServiceData> {
+    pub domain: ServiceDomain,
+    pub service: ServiceId,
+    pub service_data: ServiceData,
+}
+
+/// A call service event is fired whenever a service is called, before it runs.
+pub type Event<ServiceData, Context> = super::super::event::Event<Type, Data<ServiceData>, Context>;
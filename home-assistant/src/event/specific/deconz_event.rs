@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+event_type!("deconz_event");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    /// The slug of the device's name
+    pub id: String,
+    pub unique_id: String,
+    #[pyo3(default)]
+    pub device_id: Option<String>,
+    /// Which button was pressed and how, encoded as the button number times 1000 plus the kind of press
+    #[pyo3(default)]
+    pub event: Option<u32>,
+    /// For cubes, which side up and how it moved
+    #[pyo3(default)]
+    pub gesture: Option<u32>,
+}
+
+/// A deCONZ event is fired when a switch or remote paired through deCONZ sends an event, like when a button is pressed.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
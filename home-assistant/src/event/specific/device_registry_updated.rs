@@ -0,0 +1,15 @@
+use pyo3::prelude::*;
+
+use crate::registry::{device::DeviceId, Action};
+
+event_type!("device_registry_updated");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    pub action: Action,
+    pub device_id: DeviceId,
+}
+
+/// A device registry updated event is fired when devices are created, updated or removed.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
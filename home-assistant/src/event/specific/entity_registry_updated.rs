@@ -0,0 +1,18 @@
+use pyo3::prelude::*;
+
+use crate::{entity_id::EntityId, registry::Action};
+
+event_type!("entity_registry_updated");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    pub action: Action,
+    pub entity_id: EntityId,
+    /// Set when the entity ID itself was changed
+    #[pyo3(default)]
+    pub old_entity_id: Option<EntityId>,
+}
+
+/// An entity registry updated event is fired when entities are created, updated or removed.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
@@ -0,0 +1,16 @@
+use pyo3::prelude::*;
+
+use crate::registry::{floor::FloorId, Action};
+
+event_type!("floor_registry_updated");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    pub action: Action,
+    /// `None` when the floors were reordered
+    pub floor_id: Option<FloorId>,
+}
+
+/// A floor registry updated event is fired when floors are created, updated, removed or reordered.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
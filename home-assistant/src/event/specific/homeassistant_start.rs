@@ -0,0 +1,6 @@
+use super::NoData;
+
+event_type!("homeassistant_start");
+
+/// A start event is fired once Home Assistant has set up every integration, just before it starts running.
+pub type Event<Context> = super::super::event::Event<Type, NoData, Context>;
@@ -0,0 +1,6 @@
+use super::NoData;
+
+event_type!("homeassistant_stop");
+
+/// A stop event is fired when Home Assistant starts shutting down.
+pub type Event<Context> = super::super::event::Event<Type, NoData, Context>;
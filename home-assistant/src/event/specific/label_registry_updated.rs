@@ -0,0 +1,15 @@
+use pyo3::prelude::*;
+
+use crate::registry::{label::LabelId, Action};
+
+event_type!("label_registry_updated");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    pub action: Action,
+    pub label_id: LabelId,
+}
+
+/// A label registry updated event is fired when labels are created, updated or removed.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
@@ -1 +1,50 @@
+use pyo3::{prelude::*, types::PyDict};
+
+/// Declares `EVENT_TYPE` and a `Type` that only extracts from that exact string
+macro_rules! event_type {
+    ($event_type:literal) => {
+        pub const EVENT_TYPE: &str = $event_type;
+
+        #[derive(Debug, Clone)]
+        pub struct Type;
+
+        impl<'py> pyo3::FromPyObject<'py> for Type {
+            fn extract_bound(ob: &pyo3::Bound<'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+                let s = pyo3::types::PyAnyMethods::extract::<&str>(ob)?;
+
+                if s == EVENT_TYPE {
+                    Ok(Type)
+                } else {
+                    Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "expected a string of value '{EVENT_TYPE}', but got {s}"
+                    )))
+                }
+            }
+        }
+    };
+}
+
+pub mod area_registry_updated;
+pub mod automation_triggered;
+pub mod call_service;
+pub mod deconz_event;
+pub mod device_registry_updated;
+pub mod entity_registry_updated;
+pub mod floor_registry_updated;
+pub mod homeassistant_start;
+pub mod homeassistant_stop;
+pub mod label_registry_updated;
 pub mod state_changed;
+pub mod zha_event;
+
+/// For events that don't carry any data
+#[derive(Debug, Clone)]
+pub struct NoData;
+
+impl<'py> FromPyObject<'py> for NoData {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        ob.downcast::<PyDict>()?;
+
+        Ok(NoData)
+    }
+}
@@ -1,24 +1,8 @@
-use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::{entity_id::EntityId, state_object::StateObject};
 
-#[derive(Debug, Clone)]
-pub struct Type;
-
-impl<'py> FromPyObject<'py> for Type {
-    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
-        let s = ob.extract::<&str>()?;
-
-        if s == "state_changed" {
-            Ok(Type)
-        } else {
-            Err(PyValueError::new_err(format!(
-                "expected a string of value 'state_changed', but got {s}"
-            )))
-        }
-    }
-}
+event_type!("state_changed");
 
 #[derive(Debug, FromPyObject)]
 #[pyo3(from_item_all)]
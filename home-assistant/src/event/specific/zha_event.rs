@@ -0,0 +1,22 @@
+use arbitrary_value::arbitrary::Arbitrary;
+use pyo3::prelude::*;
+
+event_type!("zha_event");
+
+#[derive(Debug, FromPyObject)]
+#[pyo3(from_item_all)]
+pub struct Data {
+    pub device_ieee: String,
+    pub unique_id: String,
+    pub device_id: String,
+    pub endpoint_id: u8,
+    pub cluster_id: u16,
+    /// Like `on`, `off` or `remote_button_short_press`, depending on the device
+    pub command: String,
+    pub args: Arbitrary,
+    #[pyo3(default)]
+    pub params: Option<Arbitrary>,
+}
+
+/// A ZHA event is fired when a Zigbee device paired through ZHA sends a command, like when a remote's button is pressed.
+pub type Event<Context> = super::super::event::Event<Type, Data, Context>;
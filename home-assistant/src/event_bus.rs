@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use emitter_and_signal::emitter::{Capacity, Emitter};
+use once_cell::sync::OnceCell;
+use pyo3::{
+    prelude::*,
+    types::{PyCFunction, PyDict, PyTuple},
+};
+use python_utils::{detach, validate_type_by_name};
+use tokio::{select, sync::mpsc, task::JoinHandle};
+
+use super::event::{context::context::Context, event::Event, event_origin::EventOrigin};
+
+#[derive(Debug)]
+pub struct EventBus(Py<PyAny>);
+
+impl<'py> FromPyObject<'py> for EventBus {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // region: Validation
+        validate_type_by_name(ob, "EventBus")?;
+        // endregion: Validation
+
+        Ok(Self(detach(ob)))
+    }
+}
+
+/// `async_listen` and the function it returns to stop listening have to run on Home Assistant's event loop,
+/// so they're called from coroutines
+fn rust_event_bus(py: Python<'_>) -> PyResult<&Bound<'_, PyModule>> {
+    static RUST_EVENT_BUS: OnceCell<Py<PyModule>> = OnceCell::new();
+
+    let rust_event_bus = RUST_EVENT_BUS
+        .get_or_try_init(|| {
+            Result::<_, PyErr>::Ok(
+                PyModule::from_code(
+                    py,
+                    c"
+async def listen(bus, event_type, listener):
+    return bus.async_listen(event_type, listener)
+
+
+async def remove(remove_listener):
+    remove_listener()
+",
+                    c"rust_event_bus.py",
+                    c"rust_event_bus",
+                )?
+                .unbind(),
+            )
+        })?
+        .bind(py);
+
+    Ok(rust_event_bus)
+}
+
+/// A listener added with [`EventBus::add_listener`], which keeps being called until it's removed
+#[derive(Debug)]
+pub(crate) struct Listener(Py<PyAny>);
+
+impl Listener {
+    pub(crate) async fn remove(self) -> PyResult<()> {
+        let future = Python::with_gil::<_, PyResult<_>>(|py| {
+            let awaitable = rust_event_bus(py)?.call_method1("remove", (self.0,))?;
+            pyo3_async_runtimes::tokio::into_future(awaitable)
+        })?;
+
+        future.await?;
+
+        Ok(())
+    }
+}
+
+/// How many events a subscriber can fall behind by before it starts missing them
+const CAPACITY: Capacity = Capacity::new_static::<64>();
+
+/// Pass to [`EventBus::listen`] to listen to every event, whatever its type
+pub const MATCH_ALL: &str = "*";
+
+impl EventBus {
+    /// Have Home Assistant call `listener` with every event of the type, until the listener is removed
+    pub(crate) async fn add_listener<Listen>(
+        &self,
+        event_type: &str,
+        listener: Listen,
+    ) -> PyResult<Listener>
+    where
+        Listen: Fn(&Bound<'_, PyTuple>, Option<&Bound<'_, PyDict>>) + Send + 'static,
+    {
+        let future = Python::with_gil::<_, PyResult<_>>(|py| {
+            let listener = PyCFunction::new_closure(py, None, None, listener)?;
+            let args = (self.0.bind(py), event_type, listener);
+            let awaitable = rust_event_bus(py)?.call_method1("listen", args)?;
+            pyo3_async_runtimes::tokio::into_future(awaitable)
+        })?;
+
+        let remove_listener = future.await?;
+
+        Ok(Listener(remove_listener))
+    }
+
+    /// Listen to events of one type, which are only listened to on the bus while the emitter has subscribers.
+    ///
+    /// Events that can't be extracted as `Type` and `Data` are skipped.
+    /// If Home Assistant won't let us listen, the emitter stops and the join handle has the error.
+    #[allow(clippy::type_complexity)]
+    pub fn listen<Type, Data>(
+        &self,
+        py: Python<'_>,
+        event_type: &str,
+    ) -> (
+        Emitter<Arc<Event<Type, Data, Py<PyAny>>>>,
+        JoinHandle<PyResult<()>>,
+    )
+    where
+        Type: Send + Sync + 'static + for<'py> FromPyObject<'py>,
+        Data: Send + Sync + 'static + for<'py> FromPyObject<'py>,
+    {
+        let bus = EventBus(self.0.clone_ref(py));
+        let event_type = event_type.to_owned();
+
+        Emitter::new(
+            |mut publisher_stream| async move {
+                while let Some(publisher) = publisher_stream.wait().await {
+                    let (event_sender, mut event_receiver) = mpsc::unbounded_channel();
+
+                    let listener =
+                        move |args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>| {
+                            if let Ok((event,)) = args.extract::<(Event<Type, Data, Py<PyAny>>,)>()
+                            {
+                                let _ = event_sender.send(event);
+                            }
+                        };
+
+                    let listener = match bus.add_listener(&event_type, listener).await {
+                        Ok(listener) => listener,
+                        Err(err) => {
+                            #[cfg(feature = "tracing")]
+                            tracing::error!(?err, event_type, "couldn't listen to the event bus");
+                            return Err(err);
+                        }
+                    };
+
+                    loop {
+                        select! {
+                            biased;
+                            _ = publisher.all_unsubscribed() => {
+                                let _res = listener.remove().await;
+
+                                #[cfg(feature = "tracing")]
+                                tracing::debug!(?_res, event_type, "stopped listening to the event bus");
+                                break;
+                            }
+                            event = event_receiver.recv() => match event {
+                                Some(event) => publisher.publish(Arc::new(event)),
+                                None => break,
+                            },
+                        }
+                    }
+                }
+
+                Ok(())
+            },
+            CAPACITY,
+        )
+    }
+
+    /// Fire an event, for instance one of our own like `"bedtime_started"` for automations we haven't ported yet.
+    ///
+    /// This goes through the thread-safe `fire`, which schedules `async_fire` on Home Assistant's event loop.
+    /// [`arbitrary_value::Arbitrary`] works as `data` for anything without a type of its own.
+    pub fn fire<Data: for<'py> IntoPyObject<'py>, Event: for<'py> IntoPyObject<'py>>(
+        &self,
+        py: Python<'_>,
+        event_type: &str,
+        data: Data,
+        origin: EventOrigin,
+        context: Option<Context<Event>>,
+    ) -> PyResult<()> {
+        let args = (event_type, data, origin, context);
+
+        self.0.call_method1(py, "fire", args)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use pyo3::{prelude::*, types::PyDict};
+
+use super::state::FanDirection;
+use crate::attributes::item;
+
+#[derive(Debug, Clone)]
+pub struct FanAttributes {
+    pub friendly_name: Option<String>,
+    pub supported_features: Option<u32>,
+    pub percentage: Option<u8>,
+    pub percentage_step: Option<f64>,
+    pub preset_mode: Option<String>,
+    pub preset_modes: Option<Vec<String>>,
+    pub oscillating: Option<bool>,
+    pub direction: Option<FanDirection>,
+}
+
+impl<'py> FromPyObject<'py> for FanAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            supported_features: item(dict, "supported_features")?,
+            percentage: item(dict, "percentage")?,
+            percentage_step: item(dict, "percentage_step")?,
+            preset_mode: item(dict, "preset_mode")?,
+            preset_modes: item(dict, "preset_modes")?,
+            oscillating: item(dict, "oscillating")?,
+            direction: item(dict, "direction")?,
+        })
+    }
+}
@@ -0,0 +1,183 @@
+use super::service::{
+    oscillate::Oscillate, set_direction::SetDirection, set_percentage::SetPercentage,
+    set_preset_mode::SetPresetMode, toggle::Toggle, turn_off::TurnOff, turn_on::TurnOn,
+};
+use super::{GetStateObjectError, HomeAssistantFan};
+use crate::state::{ErrorState, HomeAssistantState, UnexpectedState};
+use protocol::fan::{
+    Direction, GetDirection, GetOscillating, GetPercentage, GetPresetMode, GetState, Percentage,
+    SetState, State,
+};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+
+#[derive(Debug, Snafu)]
+pub enum GetStateError {
+    GetStateObjectError { source: GetStateObjectError },
+    Error { state: ErrorState },
+    UnexpectedError { state: UnexpectedState },
+}
+
+impl GetState for HomeAssistantFan {
+    type Error = GetStateError;
+
+    async fn get_state(&self) -> Result<State, Self::Error> {
+        let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
+        let state = state_object.state;
+
+        match state {
+            HomeAssistantState::Ok(fan_state) => Ok(fan_state.into()),
+            HomeAssistantState::Err(error_state) => {
+                Err(GetStateError::Error { state: error_state })
+            }
+            HomeAssistantState::UnexpectedErr(state) => {
+                Err(GetStateError::UnexpectedError { state })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum GetPercentageError {
+    GetStateObjectError {
+        source: GetStateObjectError,
+    },
+    #[snafu(display("{percentage} is not a valid percentage"))]
+    OutOfRange {
+        percentage: u8,
+    },
+}
+
+impl GetPercentage for HomeAssistantFan {
+    type Error = GetPercentageError;
+
+    async fn get_percentage(&self) -> Result<Option<Percentage>, Self::Error> {
+        let state_object = self
+            .get_state_object()
+            .context(get_percentage_error::GetStateObjectSnafu)?;
+
+        state_object
+            .attributes
+            .percentage
+            .map(|percentage| {
+                Percentage::new(percentage).ok_or(GetPercentageError::OutOfRange { percentage })
+            })
+            .transpose()
+    }
+}
+
+impl GetPresetMode for HomeAssistantFan {
+    type Error = GetStateObjectError;
+
+    async fn get_preset_mode(&self) -> Result<Option<String>, Self::Error> {
+        let state_object = self.get_state_object()?;
+
+        Ok(state_object.attributes.preset_mode)
+    }
+}
+
+impl GetOscillating for HomeAssistantFan {
+    type Error = GetStateObjectError;
+
+    async fn get_oscillating(&self) -> Result<Option<bool>, Self::Error> {
+        let state_object = self.get_state_object()?;
+
+        Ok(state_object.attributes.oscillating)
+    }
+}
+
+impl GetDirection for HomeAssistantFan {
+    type Error = GetStateObjectError;
+
+    async fn get_direction(&self) -> Result<Option<Direction>, Self::Error> {
+        let state_object = self.get_state_object()?;
+
+        Ok(state_object.attributes.direction.map(Into::into))
+    }
+}
+
+impl SetState for HomeAssistantFan {
+    type Error = PyErr;
+
+    async fn set_state(&mut self, state: State) -> Result<(), Self::Error> {
+        let entity_id = self.entity_id();
+
+        match state {
+            State::Off => {
+                self.home_assistant
+                    .call_service_without_response(TurnOff { entity_id })
+                    .await
+            }
+            State::On => {
+                self.home_assistant
+                    .call_service_without_response(TurnOn { entity_id })
+                    .await
+            }
+        }
+    }
+}
+
+impl protocol::fan::Toggle for HomeAssistantFan {
+    type Error = PyErr;
+
+    async fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Toggle {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl protocol::fan::SetPercentage for HomeAssistantFan {
+    type Error = PyErr;
+
+    async fn set_percentage(&mut self, percentage: Percentage) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(SetPercentage {
+                entity_id: self.entity_id(),
+                percentage: percentage.get(),
+            })
+            .await
+    }
+}
+
+impl protocol::fan::SetPresetMode for HomeAssistantFan {
+    type Error = PyErr;
+
+    async fn set_preset_mode(&mut self, preset_mode: String) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(SetPresetMode {
+                entity_id: self.entity_id(),
+                preset_mode,
+            })
+            .await
+    }
+}
+
+impl protocol::fan::Oscillate for HomeAssistantFan {
+    type Error = PyErr;
+
+    async fn set_oscillating(&mut self, oscillating: bool) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Oscillate {
+                entity_id: self.entity_id(),
+                oscillating,
+            })
+            .await
+    }
+}
+
+impl protocol::fan::SetDirection for HomeAssistantFan {
+    type Error = PyErr;
+
+    async fn set_direction(&mut self, direction: Direction) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(SetDirection {
+                entity_id: self.entity_id(),
+                direction: direction.into(),
+            })
+            .await
+    }
+}
@@ -0,0 +1,7 @@
+pub mod oscillate;
+pub mod set_direction;
+pub mod set_percentage;
+pub mod set_preset_mode;
+pub mod toggle;
+pub mod turn_off;
+pub mod turn_on;
@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct Oscillate {
+    pub entity_id: EntityId,
+    pub oscillating: bool,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct OscillateServiceData {
+    entity_id: EntityId,
+    oscillating: bool,
+}
+
+impl IntoServiceCall for Oscillate {
+    type ServiceData = OscillateServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("fan").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("oscillate").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            entity_id,
+            oscillating,
+        } = self;
+        let service_data = OscillateServiceData {
+            entity_id,
+            oscillating,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    fan::state::FanDirection,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct SetDirection {
+    pub entity_id: EntityId,
+    pub direction: FanDirection,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct SetDirectionServiceData {
+    entity_id: EntityId,
+    direction: FanDirection,
+}
+
+impl IntoServiceCall for SetDirection {
+    type ServiceData = SetDirectionServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("fan").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("set_direction").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            entity_id,
+            direction,
+        } = self;
+        let service_data = SetDirectionServiceData {
+            entity_id,
+            direction,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
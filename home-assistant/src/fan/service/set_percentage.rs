@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct SetPercentage {
+    pub entity_id: EntityId,
+    pub percentage: u8,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct SetPercentageServiceData {
+    entity_id: EntityId,
+    percentage: u8,
+}
+
+impl IntoServiceCall for SetPercentage {
+    type ServiceData = SetPercentageServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("fan").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("set_percentage").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            entity_id,
+            percentage,
+        } = self;
+        let service_data = SetPercentageServiceData {
+            entity_id,
+            percentage,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct SetPresetMode {
+    pub entity_id: EntityId,
+    pub preset_mode: String,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct SetPresetModeServiceData {
+    entity_id: EntityId,
+    preset_mode: String,
+}
+
+impl IntoServiceCall for SetPresetMode {
+    type ServiceData = SetPresetModeServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("fan").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("set_preset_mode").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            entity_id,
+            preset_mode,
+        } = self;
+        let service_data = SetPresetModeServiceData {
+            entity_id,
+            preset_mode,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
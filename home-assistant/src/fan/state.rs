@@ -0,0 +1,86 @@
+use std::{convert::Infallible, str::FromStr};
+
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyString};
+use strum::EnumString;
+
+#[derive(Debug, Clone, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum FanState {
+    On,
+    Off,
+}
+
+impl<'py> FromPyObject<'py> for FanState {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let state = FanState::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+impl From<FanState> for protocol::fan::State {
+    fn from(fan_state: FanState) -> Self {
+        match fan_state {
+            FanState::On => protocol::fan::State::On,
+            FanState::Off => protocol::fan::State::Off,
+        }
+    }
+}
+
+impl From<protocol::fan::State> for FanState {
+    fn from(state: protocol::fan::State) -> Self {
+        match state {
+            protocol::fan::State::On => FanState::On,
+            protocol::fan::State::Off => FanState::Off,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum FanDirection {
+    Forward,
+    Reverse,
+}
+
+impl<'py> FromPyObject<'py> for FanDirection {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let direction =
+            FanDirection::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(direction)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for FanDirection {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let s = self.to_string();
+        s.into_pyobject(py)
+    }
+}
+
+impl From<FanDirection> for protocol::fan::Direction {
+    fn from(fan_direction: FanDirection) -> Self {
+        match fan_direction {
+            FanDirection::Forward => protocol::fan::Direction::Forward,
+            FanDirection::Reverse => protocol::fan::Direction::Reverse,
+        }
+    }
+}
+
+impl From<protocol::fan::Direction> for FanDirection {
+    fn from(direction: protocol::fan::Direction) -> Self {
+        match direction {
+            protocol::fan::Direction::Forward => FanDirection::Forward,
+            protocol::fan::Direction::Reverse => FanDirection::Reverse,
+        }
+    }
+}
@@ -2,9 +2,15 @@ use std::convert::Infallible;
 
 use pyo3::prelude::*;
 
-use python_utils::{detach, validate_type_by_name};
+use python_utils::{detach, validate_type_by_name, IsNone};
 
-use super::{service_registry::ServiceRegistry, state_machine::StateMachine};
+use super::{
+    event::context::context::Context,
+    event_bus::EventBus,
+    service::{service_target::ServiceTarget, IntoServiceCall},
+    service_registry::ServiceRegistry,
+    state_machine::StateMachine,
+};
 
 #[derive(Debug)]
 pub struct HomeAssistant(Py<PyAny>);
@@ -57,4 +63,27 @@ impl HomeAssistant {
         let services = self.0.getattr(py, "services")?;
         services.extract(py)
     }
+
+    pub fn bus(&self, py: Python<'_>) -> Result<EventBus, PyErr> {
+        let bus = self.0.getattr(py, "bus")?;
+        bus.extract(py)
+    }
+
+    /// Call a service that carries its own target in its service data and has no response,
+    /// like the ones entities call to control themselves
+    pub(crate) async fn call_service_without_response<ServiceData: for<'py> IntoPyObject<'py>>(
+        &self,
+        service_call: impl IntoServiceCall<ServiceData = ServiceData>,
+    ) -> PyResult<()> {
+        let context: Option<Context<()>> = None;
+        let target: Option<ServiceTarget> = None;
+
+        let services = Python::with_gil(|py| self.services(py))?;
+
+        let _: IsNone = services
+            .call_service(service_call, context, target, false)
+            .await?;
+
+        Ok(())
+    }
 }
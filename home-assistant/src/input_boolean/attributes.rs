@@ -0,0 +1,31 @@
+use arbitrary_value::map::Map;
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::attributes::{extra, item};
+
+#[derive(Debug, Clone)]
+pub struct InputBooleanAttributes {
+    pub friendly_name: Option<String>,
+    pub editable: Option<bool>,
+    pub icon: Option<String>,
+
+    /// Every attribute not listed above, kept as-is
+    pub extra: Map,
+}
+
+const KNOWN_ATTRIBUTES: &[&str] = &["friendly_name", "editable", "icon"];
+
+impl<'py> FromPyObject<'py> for InputBooleanAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        let extra = extra(dict, KNOWN_ATTRIBUTES)?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            editable: item(dict, "editable")?,
+            icon: item(dict, "icon")?,
+            extra,
+        })
+    }
+}
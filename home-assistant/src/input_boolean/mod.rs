@@ -0,0 +1,71 @@
+use std::{future::Future, sync::Arc};
+
+use attributes::InputBooleanAttributes;
+use emitter_and_signal::signal::{JoinError, Signal};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+use state::InputBooleanState;
+
+use crate::state::HomeAssistantState;
+
+use super::{
+    domain::Domain, entity_id::EntityId, home_assistant::HomeAssistant, object_id::ObjectId,
+    state_object::StateObject,
+};
+
+pub mod attributes;
+mod protocol;
+mod service;
+pub mod state;
+
+pub type InputBooleanStateObject =
+    StateObject<HomeAssistantState<InputBooleanState>, InputBooleanAttributes, Py<PyAny>>;
+
+pub type InputBooleanStore = Signal<Option<Arc<InputBooleanStateObject>>>;
+
+#[derive(Debug)]
+pub struct HomeAssistantInputBoolean {
+    pub home_assistant: HomeAssistant,
+    pub object_id: ObjectId,
+}
+
+impl HomeAssistantInputBoolean {
+    fn entity_id(&self) -> EntityId {
+        EntityId(Domain::InputBoolean, self.object_id.clone())
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum GetStateObjectError {
+    PythonError { source: PyErr },
+    EntityMissing,
+}
+
+impl HomeAssistantInputBoolean {
+    fn get_state_object(&self) -> Result<InputBooleanStateObject, GetStateObjectError> {
+        Python::with_gil(|py| {
+            let states = self.home_assistant.states(py).context(PythonSnafu)?;
+            let entity_id = self.entity_id();
+            let state_object = states
+                .get(py, entity_id)
+                .context(PythonSnafu)?
+                .ok_or(GetStateObjectError::EntityMissing)?;
+
+            Ok(state_object)
+        })
+    }
+
+    /// Follow the state as it changes, which is `None` while the entity doesn't exist.
+    ///
+    /// The signal only updates while the returned future is being polled.
+    pub fn store(
+        &self,
+    ) -> PyResult<(
+        InputBooleanStore,
+        impl Future<Output = Result<(), JoinError>>,
+    )> {
+        Python::with_gil(|py| {
+            InputBooleanStateObject::store(py, &self.home_assistant, self.entity_id())
+        })
+    }
+}
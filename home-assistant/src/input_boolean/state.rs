@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use strum::EnumString;
+
+#[derive(Debug, Clone, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum InputBooleanState {
+    On,
+    Off,
+}
+
+impl<'py> FromPyObject<'py> for InputBooleanState {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let state = InputBooleanState::from_str(&s)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+impl From<InputBooleanState> for protocol::on_off::State {
+    fn from(state: InputBooleanState) -> Self {
+        match state {
+            InputBooleanState::On => protocol::on_off::State::On,
+            InputBooleanState::Off => protocol::on_off::State::Off,
+        }
+    }
+}
+
+impl From<protocol::on_off::State> for InputBooleanState {
+    fn from(state: protocol::on_off::State) -> Self {
+        match state {
+            protocol::on_off::State::On => InputBooleanState::On,
+            protocol::on_off::State::Off => InputBooleanState::Off,
+        }
+    }
+}
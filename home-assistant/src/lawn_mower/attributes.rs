@@ -0,0 +1,20 @@
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::attributes::item;
+
+#[derive(Debug, Clone)]
+pub struct LawnMowerAttributes {
+    pub friendly_name: Option<String>,
+    pub supported_features: Option<u32>,
+}
+
+impl<'py> FromPyObject<'py> for LawnMowerAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            supported_features: item(dict, "supported_features")?,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use attributes::LawnMowerAttributes;
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+use state::LawnMowerState;
+
+use crate::state::HomeAssistantState;
+
+use super::{
+    domain::Domain, entity_id::EntityId, home_assistant::HomeAssistant, object_id::ObjectId,
+    state_object::StateObject,
+};
+
+pub mod attributes;
+mod protocol;
+mod service;
+pub mod state;
+
+#[derive(Debug)]
+pub struct HomeAssistantLawnMower {
+    pub home_assistant: HomeAssistant,
+    pub object_id: ObjectId,
+}
+
+impl HomeAssistantLawnMower {
+    fn entity_id(&self) -> EntityId {
+        EntityId(Domain::LawnMower, self.object_id.clone())
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum GetStateObjectError {
+    PythonError { source: PyErr },
+    EntityMissing,
+}
+
+impl HomeAssistantLawnMower {
+    pub fn get_state_object(
+        &self,
+    ) -> Result<
+        StateObject<HomeAssistantState<LawnMowerState>, LawnMowerAttributes, Py<PyAny>>,
+        GetStateObjectError,
+    > {
+        Python::with_gil(|py| {
+            let states = self.home_assistant.states(py).context(PythonSnafu)?;
+            let entity_id = self.entity_id();
+            let state_object = states
+                .get(py, entity_id)
+                .context(PythonSnafu)?
+                .ok_or(GetStateObjectError::EntityMissing)?;
+
+            Ok(state_object)
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use super::service::{dock::Dock, pause::Pause, start_mowing::StartMowing};
+use super::{GetStateObjectError, HomeAssistantLawnMower};
+use crate::state::{ErrorState, HomeAssistantState, UnexpectedState};
+use protocol::lawn_mower::{Activity, GetActivity, ReturnToDock, Start};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+
+#[derive(Debug, Snafu)]
+pub enum GetActivityError {
+    GetStateObjectError { source: GetStateObjectError },
+    Error { state: ErrorState },
+    UnexpectedError { state: UnexpectedState },
+}
+
+impl GetActivity for HomeAssistantLawnMower {
+    type Error = GetActivityError;
+
+    async fn get_activity(&self) -> Result<Activity, Self::Error> {
+        let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
+        let state = state_object.state;
+
+        match state {
+            HomeAssistantState::Ok(lawn_mower_state) => Ok(lawn_mower_state.into()),
+            HomeAssistantState::Err(error_state) => {
+                Err(GetActivityError::Error { state: error_state })
+            }
+            HomeAssistantState::UnexpectedErr(state) => {
+                Err(GetActivityError::UnexpectedError { state })
+            }
+        }
+    }
+}
+
+impl Start for HomeAssistantLawnMower {
+    type Error = PyErr;
+
+    async fn start(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(StartMowing {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl protocol::lawn_mower::Pause for HomeAssistantLawnMower {
+    type Error = PyErr;
+
+    async fn pause(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Pause {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl ReturnToDock for HomeAssistantLawnMower {
+    type Error = PyErr;
+
+    async fn return_to_dock(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Dock {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dock;
+pub mod pause;
+pub mod start_mowing;
@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use strum::EnumString;
+
+#[derive(Debug, Clone, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum LawnMowerState {
+    Mowing,
+    Docked,
+    Paused,
+    Returning,
+    Error,
+}
+
+impl<'py> FromPyObject<'py> for LawnMowerState {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let state =
+            LawnMowerState::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+impl From<LawnMowerState> for protocol::lawn_mower::Activity {
+    fn from(lawn_mower_state: LawnMowerState) -> Self {
+        match lawn_mower_state {
+            LawnMowerState::Mowing => protocol::lawn_mower::Activity::Mowing,
+            LawnMowerState::Docked => protocol::lawn_mower::Activity::Docked,
+            LawnMowerState::Paused => protocol::lawn_mower::Activity::Paused,
+            LawnMowerState::Returning => protocol::lawn_mower::Activity::Returning,
+            LawnMowerState::Error => protocol::lawn_mower::Activity::Error,
+        }
+    }
+}
@@ -1,13 +1,24 @@
+mod attributes;
 pub mod domain;
 pub mod entity_id;
 pub mod event;
+pub mod event_bus;
+pub mod fan;
 pub mod home_assistant;
+pub mod input_boolean;
+pub mod lawn_mower;
 pub mod light;
 pub mod logger;
+pub mod media_player;
 pub mod object_id;
+pub mod registry;
+pub mod sensor;
 pub mod service;
 pub mod service_registry;
 pub mod slug;
 pub mod state;
 pub mod state_machine;
 pub mod state_object;
+pub mod switch;
+pub mod vacuum;
+pub mod virtual_entity;
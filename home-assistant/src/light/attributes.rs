@@ -1,8 +1,119 @@
-use pyo3::prelude::*;
+use std::str::FromStr;
 
-#[derive(Debug, FromPyObject)]
-#[pyo3(from_item_all)]
+use arbitrary_value::map::Map;
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+use strum::EnumString;
+
+use crate::attributes::{extra, item};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ColorMode {
+    Unknown,
+    Onoff,
+    Brightness,
+    ColorTemp,
+    Hs,
+    Xy,
+    Rgb,
+    Rgbw,
+    Rgbww,
+    White,
+}
+
+impl ColorMode {
+    pub fn is_color(self) -> bool {
+        matches!(
+            self,
+            ColorMode::Hs | ColorMode::Xy | ColorMode::Rgb | ColorMode::Rgbw | ColorMode::Rgbww
+        )
+    }
+
+    pub fn is_dimmable(self) -> bool {
+        !matches!(self, ColorMode::Unknown | ColorMode::Onoff)
+    }
+}
+
+impl<'py> FromPyObject<'py> for ColorMode {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let color_mode =
+            ColorMode::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(color_mode)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct LightAttributes {
-    min_color_temp_kelvin: Option<u16>, // TODO: only here to allow compilation!
-    max_color_temp_kelvin: Option<u16>, // TODO: only here to allow compilation!
+    pub friendly_name: Option<String>,
+    pub supported_features: Option<u32>,
+
+    pub color_mode: Option<ColorMode>,
+    pub supported_color_modes: Option<Vec<ColorMode>>,
+    /// From 0 to 255
+    pub brightness: Option<u8>,
+
+    pub color_temp_kelvin: Option<u16>,
+    pub min_color_temp_kelvin: Option<u16>,
+    pub max_color_temp_kelvin: Option<u16>,
+
+    /// Hue in degrees and saturation as a percentage
+    pub hs_color: Option<(f64, f64)>,
+    pub xy_color: Option<(f64, f64)>,
+    pub rgb_color: Option<(u8, u8, u8)>,
+    pub rgbw_color: Option<(u8, u8, u8, u8)>,
+    pub rgbww_color: Option<(u8, u8, u8, u8, u8)>,
+
+    pub effect: Option<String>,
+    pub effect_list: Option<Vec<String>>,
+
+    /// Every attribute not listed above, kept as-is
+    pub extra: Map,
+}
+
+const KNOWN_ATTRIBUTES: &[&str] = &[
+    "friendly_name",
+    "supported_features",
+    "color_mode",
+    "supported_color_modes",
+    "brightness",
+    "color_temp_kelvin",
+    "min_color_temp_kelvin",
+    "max_color_temp_kelvin",
+    "hs_color",
+    "xy_color",
+    "rgb_color",
+    "rgbw_color",
+    "rgbww_color",
+    "effect",
+    "effect_list",
+];
+
+impl<'py> FromPyObject<'py> for LightAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        let extra = extra(dict, KNOWN_ATTRIBUTES)?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            supported_features: item(dict, "supported_features")?,
+            color_mode: item(dict, "color_mode")?,
+            supported_color_modes: item(dict, "supported_color_modes")?,
+            brightness: item(dict, "brightness")?,
+            color_temp_kelvin: item(dict, "color_temp_kelvin")?,
+            min_color_temp_kelvin: item(dict, "min_color_temp_kelvin")?,
+            max_color_temp_kelvin: item(dict, "max_color_temp_kelvin")?,
+            hs_color: item(dict, "hs_color")?,
+            xy_color: item(dict, "xy_color")?,
+            rgb_color: item(dict, "rgb_color")?,
+            rgbw_color: item(dict, "rgbw_color")?,
+            rgbww_color: item(dict, "rgbww_color")?,
+            effect: item(dict, "effect")?,
+            effect_list: item(dict, "effect_list")?,
+            extra,
+        })
+    }
 }
@@ -1,3 +1,4 @@
+use ::protocol::color::Gamut;
 use attributes::LightAttributes;
 use pyo3::prelude::*;
 use snafu::{ResultExt, Snafu};
@@ -10,15 +11,18 @@ use super::{
     state_object::StateObject,
 };
 
-mod attributes;
+pub mod attributes;
 mod protocol;
-mod service;
-mod state;
+pub mod service;
+pub mod state;
 
 #[derive(Debug)]
 pub struct HomeAssistantLight {
     pub home_assistant: HomeAssistant,
     pub object_id: ObjectId,
+    /// The colors the light can make, which Home Assistant doesn't report.
+    /// [`Gamut::SRGB`] is a safe guess for lights whose gamut isn't published.
+    pub gamut: Gamut,
 }
 
 impl HomeAssistantLight {
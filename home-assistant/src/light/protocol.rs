@@ -1,12 +1,22 @@
-use super::service::{turn_off::TurnOff, turn_on::TurnOn};
-use super::{state::LightState, GetStateObjectError, HomeAssistantLight};
-use crate::{
-    event::context::context::Context,
-    state::{ErrorState, HomeAssistantState, UnexpectedState},
+use super::service::{
+    toggle::Toggle,
+    turn_off::TurnOff,
+    turn_on::{TurnOn, TurnOnColor},
+};
+use super::{
+    attributes::{ColorMode, LightAttributes},
+    state::LightState,
+    GetStateObjectError, HomeAssistantLight,
+};
+use crate::state::{ErrorState, HomeAssistantState, UnexpectedState};
+use palette::{encoding::Srgb, Hsv, IntoColor};
+use protocol::color::{Chromaticity, Gamut};
+use protocol::light::{
+    ApplySetting, Brightness, Capabilities, ColorSetting, GetSetting, GetState, Kelvin,
+    LightCapabilities, LightSetting, Oklch, SetState, TemperatureRange, TurnToBrightness,
+    TurnToColor, TurnToTemperature,
 };
-use protocol::light::{GetState, SetState};
 use pyo3::prelude::*;
-use python_utils::IsNone;
 use snafu::{ResultExt, Snafu};
 
 #[derive(Debug, Snafu)]
@@ -21,17 +31,18 @@ impl GetState for HomeAssistantLight {
 
     async fn get_state(&self) -> Result<protocol::light::State, Self::Error> {
         let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
-        let state = state_object.state;
 
-        match state {
-            HomeAssistantState::Ok(light_state) => Ok(light_state.into()),
-            HomeAssistantState::Err(error_state) => {
-                Err(GetStateError::Error { state: error_state })
-            }
-            HomeAssistantState::UnexpectedErr(state) => {
-                Err(GetStateError::UnexpectedError { state })
-            }
-        }
+        into_state(state_object.state)
+    }
+}
+
+fn into_state(
+    state: HomeAssistantState<LightState>,
+) -> Result<protocol::light::State, GetStateError> {
+    match state {
+        HomeAssistantState::Ok(light_state) => Ok(light_state.into()),
+        HomeAssistantState::Err(error_state) => Err(GetStateError::Error { state: error_state }),
+        HomeAssistantState::UnexpectedErr(state) => Err(GetStateError::UnexpectedError { state }),
     }
 }
 
@@ -39,38 +50,247 @@ impl SetState for HomeAssistantLight {
     type Error = PyErr;
 
     async fn set_state(&mut self, state: protocol::light::State) -> Result<(), Self::Error> {
-        let context: Option<Context<()>> = None;
-        let target: Option<()> = None;
-
-        let services = Python::with_gil(|py| self.home_assistant.services(py))?;
+        let entity_id = self.entity_id();
 
-        let _: IsNone = match state {
+        match state {
             protocol::light::State::Off => {
-                services
-                    .call_service(
-                        TurnOff {
-                            entity_id: self.entity_id(),
-                        },
-                        context,
-                        target,
-                        false,
-                    )
+                self.home_assistant
+                    .call_service_without_response(TurnOff {
+                        target: entity_id.into(),
+                        transition: None,
+                    })
                     .await
             }
             protocol::light::State::On => {
-                services
-                    .call_service(
-                        TurnOn {
-                            entity_id: self.entity_id(),
-                        },
-                        context,
-                        target,
-                        false,
-                    )
+                self.home_assistant
+                    .call_service_without_response(TurnOn::new(entity_id))
                     .await
             }
-        }?;
+        }
+    }
+}
+
+impl protocol::light::Toggle for HomeAssistantLight {
+    type Error = PyErr;
+
+    /// Home Assistant reads and flips the state itself, so concurrent toggles don't cancel out
+    async fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Toggle {
+                target: self.entity_id().into(),
+            })
+            .await
+    }
+}
+
+/// The color fitted to the light's gamut as an xy color, which Home Assistant passes along
+/// to the integration as is (or converts for lights that don't take xy),
+/// and the brightness as a percentage.
+///
+/// The brightness is at least 1%, since Home Assistant turns the light off at 0
+/// and turning to a color should never do that.
+fn to_xy_color(color: Oklch, gamut: &Gamut) -> (TurnOnColor, u8) {
+    let color = gamut.map(color);
+    let Chromaticity { x, y } = Chromaticity::of(color);
+
+    (
+        TurnOnColor::Xy(x, y),
+        (gamut.brightness(color) * 100.0).round().max(1.0) as u8,
+    )
+}
+
+/// The hue and saturation as a color at full brightness, since Home Assistant keeps brightness separately
+fn from_hs_color((hue, saturation): (f64, f64)) -> Oklch {
+    let hsv: Hsv<Srgb, f64> = Hsv::new(hue, saturation / 100.0, 1.0);
+    hsv.into_color()
+}
+
+/// From Home Assistant's 0 to 255
+fn from_brightness(brightness: u8) -> Brightness {
+    Brightness::new_saturating((f64::from(brightness) * 100.0 / 255.0).round() as u8)
+}
+
+impl TurnToTemperature for HomeAssistantLight {
+    type Error = PyErr;
+
+    async fn turn_to_temperature(&mut self, temperature: Kelvin) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(TurnOn {
+                color_temp_kelvin: Some(temperature.get()),
+                ..TurnOn::new(self.entity_id())
+            })
+            .await
+    }
+}
+
+impl TurnToColor for HomeAssistantLight {
+    type Error = PyErr;
+
+    async fn turn_to_color(&mut self, color: Oklch) -> Result<(), Self::Error> {
+        let (color, brightness_pct) = to_xy_color(color, &self.gamut);
+
+        self.home_assistant
+            .call_service_without_response(TurnOn {
+                color: Some(color),
+                brightness_pct: Some(brightness_pct),
+                ..TurnOn::new(self.entity_id())
+            })
+            .await
+    }
+}
+
+impl TurnToBrightness for HomeAssistantLight {
+    type Error = PyErr;
+
+    async fn turn_to_brightness(&mut self, brightness: Brightness) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(TurnOn {
+                brightness_pct: Some(brightness.get()),
+                ..TurnOn::new(self.entity_id())
+            })
+            .await
+    }
+}
+
+impl ApplySetting for HomeAssistantLight {
+    type Error = PyErr;
+
+    /// Applied in a single service call, so the light transitions everything at once
+    async fn apply_setting(&mut self, setting: LightSetting) -> Result<(), Self::Error> {
+        let entity_id = self.entity_id();
+
+        if setting.state.is_off() {
+            return self
+                .home_assistant
+                .call_service_without_response(TurnOff {
+                    target: entity_id.into(),
+                    transition: setting.transition,
+                })
+                .await;
+        }
+
+        let mut turn_on = TurnOn {
+            brightness_pct: setting.brightness.map(|brightness| brightness.get()),
+            transition: setting.transition,
+            ..TurnOn::new(entity_id)
+        };
+
+        match setting.color {
+            Some(ColorSetting::Color(color)) => {
+                let (color, brightness_pct) = to_xy_color(color, &self.gamut);
+                turn_on.color = Some(color);
+                turn_on.brightness_pct = turn_on.brightness_pct.or(Some(brightness_pct));
+            }
+            Some(ColorSetting::Temperature(temperature)) => {
+                turn_on.color_temp_kelvin = Some(temperature.get());
+            }
+            None => {}
+        }
+
+        self.home_assistant
+            .call_service_without_response(turn_on)
+            .await
+    }
+}
+
+impl GetSetting for HomeAssistantLight {
+    type Error = GetStateError;
+
+    /// Home Assistant doesn't report color or brightness while the light is off, so those are left out then
+    async fn get_setting(&self) -> Result<LightSetting, Self::Error> {
+        let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
+        let state = into_state(state_object.state)?;
+        let attributes = state_object.attributes;
+
+        let color = match attributes.color_mode {
+            Some(ColorMode::ColorTemp) => attributes
+                .color_temp_kelvin
+                .map(|kelvin| ColorSetting::Temperature(Kelvin::new_saturating(kelvin))),
+            Some(color_mode) if color_mode.is_color() => attributes
+                .hs_color
+                .map(|hs_color| ColorSetting::Color(from_hs_color(hs_color))),
+            _ => None,
+        };
+
+        Ok(LightSetting {
+            state,
+            color,
+            brightness: attributes.brightness.map(from_brightness),
+            transition: None,
+        })
+    }
+}
+
+impl Capabilities for HomeAssistantLight {
+    type Error = GetStateObjectError;
+
+    async fn capabilities(&self) -> Result<LightCapabilities, Self::Error> {
+        let state_object = self.get_state_object()?;
+        let LightAttributes {
+            supported_color_modes,
+            min_color_temp_kelvin,
+            max_color_temp_kelvin,
+            ..
+        } = state_object.attributes;
+
+        let supported_color_modes = supported_color_modes.unwrap_or_default();
+
+        let dimmable = supported_color_modes
+            .iter()
+            .any(|color_mode| color_mode.is_dimmable());
+        let color = supported_color_modes
+            .iter()
+            .any(|color_mode| color_mode.is_color());
+
+        let temperature = if supported_color_modes.contains(&ColorMode::ColorTemp) {
+            min_color_temp_kelvin
+                .zip(max_color_temp_kelvin)
+                .map(|(min, max)| TemperatureRange {
+                    min: Kelvin::new_saturating(min),
+                    max: Kelvin::new_saturating(max),
+                })
+        } else {
+            None
+        };
+
+        Ok(LightCapabilities {
+            dimmable,
+            color,
+            temperature,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol::{color::Gamut, light::Oklch};
+
+    use super::{from_brightness, to_xy_color, TurnOnColor};
+
+    #[test]
+    fn white_is_the_white_point_at_full_brightness() {
+        let (color, brightness_pct) = to_xy_color(Oklch::new(1.0, 0.0, 0.0), &Gamut::HUE_C);
+
+        let TurnOnColor::Xy(x, y) = color else {
+            panic!("expected an xy color, got {color:?}");
+        };
+        assert!((x - 0.3127).abs() < 0.001, "x = {x}");
+        assert!((y - 0.3290).abs() < 0.001, "y = {y}");
+        assert_eq!(brightness_pct, 100);
+    }
+
+    /// A brightness of 0 would turn the light off instead of making it dim
+    #[test]
+    fn colors_too_dark_to_round_up_still_get_some_brightness() {
+        let (_, brightness_pct) = to_xy_color(Oklch::new(0.01, 0.01, 30.0), &Gamut::HUE_C);
+
+        assert_eq!(brightness_pct, 1);
+    }
 
-        Ok(())
+    #[test]
+    fn brightness_is_rescaled_to_a_percentage() {
+        assert_eq!(from_brightness(0).get(), 0);
+        assert_eq!(from_brightness(128).get(), 50);
+        assert_eq!(from_brightness(255).get(), 100);
     }
 }
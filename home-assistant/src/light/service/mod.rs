@@ -1,2 +1,3 @@
+pub mod toggle;
 pub mod turn_off;
 pub mod turn_on;
@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, service_target::ServiceTarget,
+    IntoServiceCall,
+};
+
+#[derive(Debug, Clone)]
+pub struct Toggle {
+    pub target: ServiceTarget,
+}
+
+impl IntoServiceCall for Toggle {
+    type ServiceData = ServiceTarget;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("light").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("toggle").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self { target } = self;
+
+        (service_domain, service_id, target)
+    }
+}
@@ -1,20 +1,38 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
-use pyo3::IntoPyObject;
+use pyo3::{prelude::*, types::PyDict};
 
-use crate::{
-    entity_id::EntityId,
-    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, service_target::ServiceTarget,
+    IntoServiceCall,
 };
 
 #[derive(Debug, Clone)]
 pub struct TurnOff {
-    pub entity_id: EntityId,
+    pub target: ServiceTarget,
+    pub transition: Option<Duration>,
 }
 
-#[derive(Debug, Clone, IntoPyObject)]
+#[derive(Debug, Clone)]
 pub struct TurnOffServiceData {
-    entity_id: EntityId,
+    target: ServiceTarget,
+    transition: Option<Duration>,
+}
+
+impl<'py> IntoPyObject<'py> for TurnOffServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let dict = self.target.into_pyobject(py)?;
+
+        if let Some(transition) = self.transition {
+            dict.set_item("transition", transition.as_secs_f64())?;
+        }
+
+        Ok(dict)
+    }
 }
 
 impl IntoServiceCall for TurnOff {
@@ -24,9 +42,9 @@ impl IntoServiceCall for TurnOff {
         let service_domain = ServiceDomain::from_str("light").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
         let service_id = ServiceId::from_str("turn_off").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
 
-        let Self { entity_id } = self;
+        let Self { target, transition } = self;
 
-        let service_data = TurnOffServiceData { entity_id };
+        let service_data = TurnOffServiceData { target, transition };
 
         (service_domain, service_id, service_data)
     }
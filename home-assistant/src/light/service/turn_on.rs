@@ -1,20 +1,90 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
-use pyo3::IntoPyObject;
+use pyo3::{prelude::*, types::PyDict};
 
-use crate::{
-    entity_id::EntityId,
-    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, service_target::ServiceTarget,
+    IntoServiceCall,
 };
 
+/// The ways `light.turn_on` takes a color, each as Home Assistant writes them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TurnOnColor {
+    /// Hue in degrees and saturation as a percentage
+    Hs(f64, f64),
+    Xy(f64, f64),
+    Rgb(u8, u8, u8),
+}
+
 #[derive(Debug, Clone)]
 pub struct TurnOn {
-    pub entity_id: EntityId,
+    pub target: ServiceTarget,
+    pub color: Option<TurnOnColor>,
+    pub color_temp_kelvin: Option<u16>,
+    /// From 0 to 255
+    pub brightness: Option<u8>,
+    pub brightness_pct: Option<u8>,
+    pub transition: Option<Duration>,
+}
+
+impl TurnOn {
+    /// Turns on to however the light was before
+    pub fn new(target: impl Into<ServiceTarget>) -> Self {
+        Self {
+            target: target.into(),
+            color: None,
+            color_temp_kelvin: None,
+            brightness: None,
+            brightness_pct: None,
+            transition: None,
+        }
+    }
 }
 
-#[derive(Debug, Clone, IntoPyObject)]
+/// Only what was asked for is sent, since Home Assistant rejects `None` for most of these
+#[derive(Debug, Clone)]
 pub struct TurnOnServiceData {
-    entity_id: EntityId,
+    target: ServiceTarget,
+    color: Option<TurnOnColor>,
+    color_temp_kelvin: Option<u16>,
+    brightness: Option<u8>,
+    brightness_pct: Option<u8>,
+    transition: Option<Duration>,
+}
+
+impl<'py> IntoPyObject<'py> for TurnOnServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let dict = self.target.into_pyobject(py)?;
+
+        match self.color {
+            Some(TurnOnColor::Hs(hue, saturation)) => {
+                dict.set_item("hs_color", (hue, saturation))?
+            }
+            Some(TurnOnColor::Xy(x, y)) => dict.set_item("xy_color", (x, y))?,
+            Some(TurnOnColor::Rgb(red, green, blue)) => {
+                dict.set_item("rgb_color", (red, green, blue))?
+            }
+            None => {}
+        }
+        if let Some(color_temp_kelvin) = self.color_temp_kelvin {
+            dict.set_item("color_temp_kelvin", color_temp_kelvin)?;
+        }
+        if let Some(brightness) = self.brightness {
+            dict.set_item("brightness", brightness)?;
+        }
+        if let Some(brightness_pct) = self.brightness_pct {
+            dict.set_item("brightness_pct", brightness_pct)?;
+        }
+        if let Some(transition) = self.transition {
+            dict.set_item("transition", transition.as_secs_f64())?;
+        }
+
+        Ok(dict)
+    }
 }
 
 impl IntoServiceCall for TurnOn {
@@ -24,8 +94,22 @@ impl IntoServiceCall for TurnOn {
         let service_domain = ServiceDomain::from_str("light").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
         let service_id = ServiceId::from_str("turn_on").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
 
-        let Self { entity_id } = self;
-        let service_data = TurnOnServiceData { entity_id };
+        let Self {
+            target,
+            color,
+            color_temp_kelvin,
+            brightness,
+            brightness_pct,
+            transition,
+        } = self;
+        let service_data = TurnOnServiceData {
+            target,
+            color,
+            color_temp_kelvin,
+            brightness,
+            brightness_pct,
+            transition,
+        };
 
         (service_domain, service_id, service_data)
     }
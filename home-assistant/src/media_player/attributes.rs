@@ -0,0 +1,159 @@
+use std::{str::FromStr, time::Duration};
+
+use arbitrary_value::map::Map;
+use chrono::{DateTime, Utc};
+use protocol::media_player::{MediaContentType, MediaMetadata};
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::attributes::{extra, item, item_as_string};
+
+#[derive(Debug, Clone)]
+pub struct MediaPlayerAttributes {
+    pub friendly_name: Option<String>,
+    pub device_class: Option<String>,
+    pub supported_features: Option<u32>,
+    pub entity_picture: Option<String>,
+
+    pub volume_level: Option<f64>,
+    pub is_volume_muted: Option<bool>,
+
+    pub source: Option<String>,
+    pub source_list: Option<Vec<String>>,
+    pub sound_mode: Option<String>,
+    pub sound_mode_list: Option<Vec<String>>,
+    pub shuffle: Option<bool>,
+    pub repeat: Option<String>,
+    pub group_members: Option<Vec<String>>,
+
+    pub media_content_id: Option<String>,
+    pub media_content_type: Option<String>,
+    pub media_duration: Option<f64>,
+    pub media_position: Option<f64>,
+    pub media_position_updated_at: Option<DateTime<Utc>>,
+    pub media_title: Option<String>,
+    pub media_artist: Option<String>,
+    pub media_album_name: Option<String>,
+    pub media_album_artist: Option<String>,
+    pub media_track: Option<u32>,
+    pub media_series_title: Option<String>,
+    /// Integrations disagree on whether this is a number or a string, so it is always made a string
+    pub media_season: Option<String>,
+    /// Integrations disagree on whether this is a number or a string, so it is always made a string
+    pub media_episode: Option<String>,
+    pub media_channel: Option<String>,
+    pub media_playlist: Option<String>,
+    pub app_id: Option<String>,
+    pub app_name: Option<String>,
+
+    /// Every attribute not listed above, kept as-is
+    pub extra: Map,
+}
+
+const KNOWN_ATTRIBUTES: &[&str] = &[
+    "friendly_name",
+    "device_class",
+    "supported_features",
+    "entity_picture",
+    "volume_level",
+    "is_volume_muted",
+    "source",
+    "source_list",
+    "sound_mode",
+    "sound_mode_list",
+    "shuffle",
+    "repeat",
+    "group_members",
+    "media_content_id",
+    "media_content_type",
+    "media_duration",
+    "media_position",
+    "media_position_updated_at",
+    "media_title",
+    "media_artist",
+    "media_album_name",
+    "media_album_artist",
+    "media_track",
+    "media_series_title",
+    "media_season",
+    "media_episode",
+    "media_channel",
+    "media_playlist",
+    "app_id",
+    "app_name",
+];
+
+impl<'py> FromPyObject<'py> for MediaPlayerAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        let extra = extra(dict, KNOWN_ATTRIBUTES)?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            device_class: item(dict, "device_class")?,
+            supported_features: item(dict, "supported_features")?,
+            entity_picture: item(dict, "entity_picture")?,
+            volume_level: item(dict, "volume_level")?,
+            is_volume_muted: item(dict, "is_volume_muted")?,
+            source: item(dict, "source")?,
+            source_list: item(dict, "source_list")?,
+            sound_mode: item(dict, "sound_mode")?,
+            sound_mode_list: item(dict, "sound_mode_list")?,
+            shuffle: item(dict, "shuffle")?,
+            repeat: item_as_string(dict, "repeat")?,
+            group_members: item(dict, "group_members")?,
+            media_content_id: item(dict, "media_content_id")?,
+            media_content_type: item_as_string(dict, "media_content_type")?,
+            media_duration: item(dict, "media_duration")?,
+            media_position: item(dict, "media_position")?,
+            media_position_updated_at: item(dict, "media_position_updated_at")?,
+            media_title: item(dict, "media_title")?,
+            media_artist: item(dict, "media_artist")?,
+            media_album_name: item(dict, "media_album_name")?,
+            media_album_artist: item(dict, "media_album_artist")?,
+            media_track: item(dict, "media_track")?,
+            media_series_title: item(dict, "media_series_title")?,
+            media_season: item_as_string(dict, "media_season")?,
+            media_episode: item_as_string(dict, "media_episode")?,
+            media_channel: item(dict, "media_channel")?,
+            media_playlist: item(dict, "media_playlist")?,
+            app_id: item(dict, "app_id")?,
+            app_name: item(dict, "app_name")?,
+            extra,
+        })
+    }
+}
+
+fn seconds_to_duration(seconds: f64) -> Option<Duration> {
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+impl From<MediaPlayerAttributes> for MediaMetadata {
+    fn from(attributes: MediaPlayerAttributes) -> Self {
+        let content_type = attributes.media_content_type.map(|content_type| {
+            match MediaContentType::from_str(&content_type) {
+                Ok(content_type) => content_type,
+                Err(_) => MediaContentType::Other(content_type),
+            }
+        });
+
+        MediaMetadata {
+            content_id: attributes.media_content_id,
+            content_type,
+            title: attributes.media_title,
+            artist: attributes.media_artist,
+            album_name: attributes.media_album_name,
+            album_artist: attributes.media_album_artist,
+            track: attributes.media_track,
+            series_title: attributes.media_series_title,
+            season: attributes.media_season,
+            episode: attributes.media_episode,
+            channel: attributes.media_channel,
+            playlist: attributes.media_playlist,
+            app_name: attributes.app_name,
+            duration: attributes.media_duration.and_then(seconds_to_duration),
+            position: attributes.media_position.and_then(seconds_to_duration),
+            image_url: attributes.entity_picture,
+        }
+    }
+}
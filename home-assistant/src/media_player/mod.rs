@@ -0,0 +1,54 @@
+use attributes::MediaPlayerAttributes;
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+use state::MediaPlayerState;
+
+use crate::state::HomeAssistantState;
+
+use super::{
+    domain::Domain, entity_id::EntityId, home_assistant::HomeAssistant, object_id::ObjectId,
+    state_object::StateObject,
+};
+
+pub mod attributes;
+mod protocol;
+mod service;
+pub mod state;
+
+#[derive(Debug)]
+pub struct HomeAssistantMediaPlayer {
+    pub home_assistant: HomeAssistant,
+    pub object_id: ObjectId,
+}
+
+impl HomeAssistantMediaPlayer {
+    fn entity_id(&self) -> EntityId {
+        EntityId(Domain::MediaPlayer, self.object_id.clone())
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum GetStateObjectError {
+    PythonError { source: PyErr },
+    EntityMissing,
+}
+
+impl HomeAssistantMediaPlayer {
+    pub fn get_state_object(
+        &self,
+    ) -> Result<
+        StateObject<HomeAssistantState<MediaPlayerState>, MediaPlayerAttributes, Py<PyAny>>,
+        GetStateObjectError,
+    > {
+        Python::with_gil(|py| {
+            let states = self.home_assistant.states(py).context(PythonSnafu)?;
+            let entity_id = self.entity_id();
+            let state_object = states
+                .get(py, entity_id)
+                .context(PythonSnafu)?
+                .ok_or(GetStateObjectError::EntityMissing)?;
+
+            Ok(state_object)
+        })
+    }
+}
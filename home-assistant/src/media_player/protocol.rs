@@ -0,0 +1,166 @@
+use super::service::{
+    media_pause::MediaPause, media_play::MediaPlay, media_stop::MediaStop, play_media::PlayMedia,
+    select_source::SelectSource, volume_down::VolumeDown, volume_mute::VolumeMute,
+    volume_set::VolumeSet, volume_up::VolumeUp,
+};
+use super::{GetStateObjectError, HomeAssistantMediaPlayer};
+use crate::state::{ErrorState, HomeAssistantState, UnexpectedState};
+use protocol::media_player::{
+    GetMediaMetadata, GetPlaybackState, Media, MediaMetadata, Pause, Play, PlaybackState, SetMuted,
+    SetVolume, StepVolume, Stop, Volume, VolumeStep,
+};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+
+#[derive(Debug, Snafu)]
+pub enum GetPlaybackStateError {
+    GetStateObjectError { source: GetStateObjectError },
+    Error { state: ErrorState },
+    UnexpectedError { state: UnexpectedState },
+}
+
+impl GetPlaybackState for HomeAssistantMediaPlayer {
+    type Error = GetPlaybackStateError;
+
+    async fn get_playback_state(&self) -> Result<PlaybackState, Self::Error> {
+        let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
+        let state = state_object.state;
+
+        match state {
+            HomeAssistantState::Ok(media_player_state) => Ok(media_player_state.into()),
+            HomeAssistantState::Err(error_state) => {
+                Err(GetPlaybackStateError::Error { state: error_state })
+            }
+            HomeAssistantState::UnexpectedErr(state) => {
+                Err(GetPlaybackStateError::UnexpectedError { state })
+            }
+        }
+    }
+}
+
+impl GetMediaMetadata for HomeAssistantMediaPlayer {
+    type Error = GetStateObjectError;
+
+    async fn get_media_metadata(&self) -> Result<MediaMetadata, Self::Error> {
+        let state_object = self.get_state_object()?;
+
+        Ok(state_object.attributes.into())
+    }
+}
+
+impl Play for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn play(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(MediaPlay {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl Pause for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn pause(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(MediaPause {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl Stop for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn stop(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(MediaStop {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl SetVolume for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn set_volume(&mut self, volume: Volume) -> Result<(), Self::Error> {
+        let volume_level = f64::from(volume.get()) / f64::from(Volume::MAX.get());
+
+        self.home_assistant
+            .call_service_without_response(VolumeSet {
+                entity_id: self.entity_id(),
+                volume_level,
+            })
+            .await
+    }
+}
+
+impl StepVolume for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn step_volume(&mut self, step: VolumeStep) -> Result<(), Self::Error> {
+        let entity_id = self.entity_id();
+
+        match step {
+            VolumeStep::Down => {
+                self.home_assistant
+                    .call_service_without_response(VolumeDown { entity_id })
+                    .await
+            }
+            VolumeStep::Up => {
+                self.home_assistant
+                    .call_service_without_response(VolumeUp { entity_id })
+                    .await
+            }
+        }
+    }
+}
+
+impl SetMuted for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn set_muted(&mut self, muted: bool) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(VolumeMute {
+                entity_id: self.entity_id(),
+                is_volume_muted: muted,
+            })
+            .await
+    }
+}
+
+impl protocol::media_player::SelectSource for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn select_source(&mut self, source: String) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(SelectSource {
+                entity_id: self.entity_id(),
+                source,
+            })
+            .await
+    }
+}
+
+impl protocol::media_player::PlayMedia for HomeAssistantMediaPlayer {
+    type Error = PyErr;
+
+    async fn play_media(&mut self, media: Media) -> Result<(), Self::Error> {
+        let Media {
+            content_id,
+            content_type,
+        } = media;
+
+        self.home_assistant
+            .call_service_without_response(PlayMedia {
+                entity_id: self.entity_id(),
+                media_content_id: content_id,
+                media_content_type: content_type.to_string(),
+            })
+            .await
+    }
+}
@@ -0,0 +1,9 @@
+pub mod media_pause;
+pub mod media_play;
+pub mod media_stop;
+pub mod play_media;
+pub mod select_source;
+pub mod volume_down;
+pub mod volume_mute;
+pub mod volume_set;
+pub mod volume_up;
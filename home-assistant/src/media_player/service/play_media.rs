@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct PlayMedia {
+    pub entity_id: EntityId,
+    pub media_content_id: String,
+    pub media_content_type: String,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct PlayMediaServiceData {
+    entity_id: EntityId,
+    media_content_id: String,
+    media_content_type: String,
+}
+
+impl IntoServiceCall for PlayMedia {
+    type ServiceData = PlayMediaServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("media_player").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("play_media").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            entity_id,
+            media_content_id,
+            media_content_type,
+        } = self;
+        let service_data = PlayMediaServiceData {
+            entity_id,
+            media_content_id,
+            media_content_type,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
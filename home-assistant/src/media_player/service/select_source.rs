@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct SelectSource {
+    pub entity_id: EntityId,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct SelectSourceServiceData {
+    entity_id: EntityId,
+    source: String,
+}
+
+impl IntoServiceCall for SelectSource {
+    type ServiceData = SelectSourceServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("media_player").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("select_source").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self { entity_id, source } = self;
+        let service_data = SelectSourceServiceData { entity_id, source };
+
+        (service_domain, service_id, service_data)
+    }
+}
@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct VolumeMute {
+    pub entity_id: EntityId,
+    pub is_volume_muted: bool,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct VolumeMuteServiceData {
+    entity_id: EntityId,
+    is_volume_muted: bool,
+}
+
+impl IntoServiceCall for VolumeMute {
+    type ServiceData = VolumeMuteServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("media_player").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("volume_mute").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            entity_id,
+            is_volume_muted,
+        } = self;
+        let service_data = VolumeMuteServiceData {
+            entity_id,
+            is_volume_muted,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
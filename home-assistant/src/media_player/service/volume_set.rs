@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use pyo3::IntoPyObject;
+
+use crate::{
+    entity_id::EntityId,
+    service::{service_domain::ServiceDomain, service_id::ServiceId, IntoServiceCall},
+};
+
+#[derive(Debug, Clone)]
+pub struct VolumeSet {
+    pub entity_id: EntityId,
+    pub volume_level: f64,
+}
+
+#[derive(Debug, Clone, IntoPyObject)]
+pub struct VolumeSetServiceData {
+    entity_id: EntityId,
+    volume_level: f64,
+}
+
+impl IntoServiceCall for VolumeSet {
+    type ServiceData = VolumeSetServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("media_player").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("volume_set").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            entity_id,
+            volume_level,
+        } = self;
+        let service_data = VolumeSetServiceData {
+            entity_id,
+            volume_level,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
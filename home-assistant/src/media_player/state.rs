@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use strum::EnumString;
+
+#[derive(Debug, Clone, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum MediaPlayerState {
+    Off,
+    On,
+    Idle,
+    Playing,
+    Paused,
+    Standby,
+    Buffering,
+}
+
+impl<'py> FromPyObject<'py> for MediaPlayerState {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let state =
+            MediaPlayerState::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+impl From<MediaPlayerState> for protocol::media_player::PlaybackState {
+    fn from(media_player_state: MediaPlayerState) -> Self {
+        match media_player_state {
+            MediaPlayerState::Off => protocol::media_player::PlaybackState::Off,
+            MediaPlayerState::On => protocol::media_player::PlaybackState::On,
+            MediaPlayerState::Idle => protocol::media_player::PlaybackState::Idle,
+            MediaPlayerState::Playing => protocol::media_player::PlaybackState::Playing,
+            MediaPlayerState::Paused => protocol::media_player::PlaybackState::Paused,
+            MediaPlayerState::Standby => protocol::media_player::PlaybackState::Standby,
+            MediaPlayerState::Buffering => protocol::media_player::PlaybackState::Buffering,
+        }
+    }
+}
@@ -6,7 +6,7 @@ use super::slug::Slug;
 
 pub use super::slug::SlugParsingError as ObjectIdParsingError;
 
-#[derive(Debug, Clone, derive_more::Display, derive_more::FromStr)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::FromStr)]
 pub struct ObjectId(pub Slug);
 
 impl<'py> IntoPyObject<'py> for ObjectId {
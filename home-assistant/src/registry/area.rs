@@ -0,0 +1,104 @@
+use std::{collections::HashSet, convert::Infallible, str::FromStr, sync::Arc};
+
+use emitter_and_signal::emitter::Emitter;
+use once_cell::sync::OnceCell;
+use pyo3::{prelude::*, types::PyString};
+use python_utils::{detach, validate_type_by_name};
+use tokio::task::JoinHandle;
+
+use super::{
+    super::{event::specific::area_registry_updated, home_assistant::HomeAssistant, slug::Slug},
+    floor::FloorId,
+    label::LabelId,
+};
+
+pub use super::super::slug::SlugParsingError as AreaIdParsingError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::FromStr)]
+pub struct AreaId(pub Slug);
+
+impl<'py> FromPyObject<'py> for AreaId {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract()?;
+        let parsed = AreaId::from_str(s)?;
+
+        Ok(parsed)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for AreaId {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let s = self.to_string();
+        s.into_pyobject(py)
+    }
+}
+
+#[derive(Debug, Clone, FromPyObject)]
+pub struct AreaEntry {
+    pub id: AreaId,
+    pub name: String,
+    pub floor_id: Option<FloorId>,
+    pub icon: Option<String>,
+    pub aliases: HashSet<String>,
+    pub labels: HashSet<LabelId>,
+}
+
+#[derive(Debug)]
+pub struct AreaRegistry(Py<PyAny>);
+
+impl<'py> FromPyObject<'py> for AreaRegistry {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // region: Validation
+        validate_type_by_name(ob, "AreaRegistry")?;
+        // endregion: Validation
+
+        Ok(Self(detach(ob)))
+    }
+}
+
+impl AreaRegistry {
+    pub fn get(py: Python<'_>, home_assistant: &HomeAssistant) -> PyResult<Self> {
+        static AREA_REGISTRY_MODULE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+        let area_registry_module = AREA_REGISTRY_MODULE
+            .get_or_try_init(|| {
+                Result::<_, PyErr>::Ok(py.import("homeassistant.helpers.area_registry")?.unbind())
+            })?
+            .bind(py);
+
+        let area_registry = area_registry_module.call_method1("async_get", (home_assistant,))?;
+        area_registry.extract()
+    }
+
+    pub fn area(&self, py: Python<'_>, area_id: AreaId) -> PyResult<Option<AreaEntry>> {
+        let area = self.0.call_method1(py, "async_get_area", (area_id,))?;
+        area.extract(py)
+    }
+
+    pub fn areas(&self, py: Python<'_>) -> PyResult<Vec<AreaEntry>> {
+        let areas = self.0.call_method0(py, "async_list_areas")?;
+        areas
+            .bind(py)
+            .try_iter()?
+            .map(|area| area?.extract())
+            .collect()
+    }
+
+    /// Areas being created, updated, removed or reordered
+    #[allow(clippy::type_complexity)]
+    pub fn updates(
+        py: Python<'_>,
+        home_assistant: &HomeAssistant,
+    ) -> PyResult<(
+        Emitter<Arc<area_registry_updated::Event<Py<PyAny>>>>,
+        JoinHandle<PyResult<()>>,
+    )> {
+        let bus = home_assistant.bus(py)?;
+
+        Ok(bus.listen(py, area_registry_updated::EVENT_TYPE))
+    }
+}
@@ -0,0 +1,112 @@
+use std::{collections::HashSet, convert::Infallible, str::FromStr, sync::Arc};
+
+use emitter_and_signal::emitter::Emitter;
+use once_cell::sync::OnceCell;
+use pyo3::{prelude::*, types::PyString};
+use python_utils::{detach, validate_type_by_name};
+use tokio::task::JoinHandle;
+
+use super::{
+    super::{event::specific::device_registry_updated, home_assistant::HomeAssistant, slug::Slug},
+    area::AreaId,
+    label::LabelId,
+};
+
+pub use super::super::slug::SlugParsingError as DeviceIdParsingError;
+
+/// Devices are given a random hexadecimal ID, which happens to also be a valid slug
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::FromStr)]
+pub struct DeviceId(pub Slug);
+
+impl<'py> FromPyObject<'py> for DeviceId {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract()?;
+        let parsed = DeviceId::from_str(s)?;
+
+        Ok(parsed)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for DeviceId {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let s = self.to_string();
+        s.into_pyobject(py)
+    }
+}
+
+#[derive(Debug, Clone, FromPyObject)]
+pub struct DeviceEntry {
+    pub id: DeviceId,
+    /// The name the integration gave the device
+    pub name: Option<String>,
+    /// The name the device was renamed to, which takes precedence over `name`
+    pub name_by_user: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub area_id: Option<AreaId>,
+    pub labels: HashSet<LabelId>,
+    /// The device this one is connected through, like a hub or a bridge
+    pub via_device_id: Option<DeviceId>,
+    pub disabled_by: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct DeviceRegistry(Py<PyAny>);
+
+impl<'py> FromPyObject<'py> for DeviceRegistry {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // region: Validation
+        validate_type_by_name(ob, "DeviceRegistry")?;
+        // endregion: Validation
+
+        Ok(Self(detach(ob)))
+    }
+}
+
+impl DeviceRegistry {
+    pub fn get(py: Python<'_>, home_assistant: &HomeAssistant) -> PyResult<Self> {
+        static DEVICE_REGISTRY_MODULE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+        let device_registry_module = DEVICE_REGISTRY_MODULE
+            .get_or_try_init(|| {
+                Result::<_, PyErr>::Ok(py.import("homeassistant.helpers.device_registry")?.unbind())
+            })?
+            .bind(py);
+
+        let device_registry =
+            device_registry_module.call_method1("async_get", (home_assistant,))?;
+        device_registry.extract()
+    }
+
+    pub fn device(&self, py: Python<'_>, device_id: DeviceId) -> PyResult<Option<DeviceEntry>> {
+        let device = self.0.call_method1(py, "async_get", (device_id,))?;
+        device.extract(py)
+    }
+
+    pub fn devices(&self, py: Python<'_>) -> PyResult<Vec<DeviceEntry>> {
+        let devices = self.0.getattr(py, "devices")?.call_method0(py, "values")?;
+        devices
+            .bind(py)
+            .try_iter()?
+            .map(|device| device?.extract())
+            .collect()
+    }
+
+    /// Devices being created, updated or removed
+    #[allow(clippy::type_complexity)]
+    pub fn updates(
+        py: Python<'_>,
+        home_assistant: &HomeAssistant,
+    ) -> PyResult<(
+        Emitter<Arc<device_registry_updated::Event<Py<PyAny>>>>,
+        JoinHandle<PyResult<()>>,
+    )> {
+        let bus = home_assistant.bus(py)?;
+
+        Ok(bus.listen(py, device_registry_updated::EVENT_TYPE))
+    }
+}
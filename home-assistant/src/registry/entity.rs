@@ -0,0 +1,107 @@
+use std::{collections::HashSet, sync::Arc};
+
+use emitter_and_signal::emitter::Emitter;
+use once_cell::sync::OnceCell;
+use pyo3::prelude::*;
+use python_utils::{detach, validate_type_by_name};
+use tokio::task::JoinHandle;
+
+use super::{
+    super::{
+        domain::Domain, entity_id::EntityId, event::specific::entity_registry_updated,
+        home_assistant::HomeAssistant,
+    },
+    area::AreaId,
+    device::DeviceId,
+    label::LabelId,
+};
+
+#[derive(Debug, Clone, FromPyObject)]
+pub struct EntityEntry {
+    pub entity_id: EntityId,
+    /// The registry's own ID for the entity, which stays the same when the entity ID changes
+    pub id: String,
+    /// The ID the integration knows the entity by, which is only unique within the platform
+    pub unique_id: String,
+    pub platform: String,
+    pub device_id: Option<DeviceId>,
+    /// Only set when the entity is in a different area than its device
+    pub area_id: Option<AreaId>,
+    pub labels: HashSet<LabelId>,
+    /// The name the entity was renamed to
+    pub name: Option<String>,
+    /// The name the integration gave the entity
+    pub original_name: Option<String>,
+    pub disabled_by: Option<String>,
+    pub hidden_by: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct EntityRegistry(Py<PyAny>);
+
+impl<'py> FromPyObject<'py> for EntityRegistry {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // region: Validation
+        validate_type_by_name(ob, "EntityRegistry")?;
+        // endregion: Validation
+
+        Ok(Self(detach(ob)))
+    }
+}
+
+impl EntityRegistry {
+    pub fn get(py: Python<'_>, home_assistant: &HomeAssistant) -> PyResult<Self> {
+        static ENTITY_REGISTRY_MODULE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+        let entity_registry_module = ENTITY_REGISTRY_MODULE
+            .get_or_try_init(|| {
+                Result::<_, PyErr>::Ok(py.import("homeassistant.helpers.entity_registry")?.unbind())
+            })?
+            .bind(py);
+
+        let entity_registry =
+            entity_registry_module.call_method1("async_get", (home_assistant,))?;
+        entity_registry.extract()
+    }
+
+    pub fn entity(&self, py: Python<'_>, entity_id: EntityId) -> PyResult<Option<EntityEntry>> {
+        let entity = self.0.call_method1(py, "async_get", (entity_id,))?;
+        entity.extract(py)
+    }
+
+    /// Entities whose entity ID isn't in a known [`Domain`] are left out
+    pub fn entities(&self, py: Python<'_>) -> PyResult<Vec<EntityEntry>> {
+        let entities = self.0.getattr(py, "entities")?.call_method0(py, "values")?;
+
+        let mut entries = Vec::new();
+        for entity in entities.bind(py).try_iter()? {
+            let entity = entity?;
+
+            let entity_id = entity.getattr("entity_id")?.extract::<String>()?;
+            let known_domain = entity_id
+                .split_once('.')
+                .is_some_and(|(domain, _)| domain.parse::<Domain>().is_ok());
+            if !known_domain {
+                continue;
+            }
+
+            entries.push(entity.extract()?);
+        }
+
+        Ok(entries)
+    }
+
+    /// Entities being created, updated or removed
+    #[allow(clippy::type_complexity)]
+    pub fn updates(
+        py: Python<'_>,
+        home_assistant: &HomeAssistant,
+    ) -> PyResult<(
+        Emitter<Arc<entity_registry_updated::Event<Py<PyAny>>>>,
+        JoinHandle<PyResult<()>>,
+    )> {
+        let bus = home_assistant.bus(py)?;
+
+        Ok(bus.listen(py, entity_registry_updated::EVENT_TYPE))
+    }
+}
@@ -0,0 +1,102 @@
+use std::{collections::HashSet, convert::Infallible, str::FromStr, sync::Arc};
+
+use emitter_and_signal::emitter::Emitter;
+use once_cell::sync::OnceCell;
+use pyo3::{prelude::*, types::PyString};
+use python_utils::{detach, validate_type_by_name};
+use tokio::task::JoinHandle;
+
+use super::super::{
+    event::specific::floor_registry_updated, home_assistant::HomeAssistant, slug::Slug,
+};
+
+pub use super::super::slug::SlugParsingError as FloorIdParsingError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::FromStr)]
+pub struct FloorId(pub Slug);
+
+impl<'py> FromPyObject<'py> for FloorId {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract()?;
+        let parsed = FloorId::from_str(s)?;
+
+        Ok(parsed)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for FloorId {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let s = self.to_string();
+        s.into_pyobject(py)
+    }
+}
+
+#[derive(Debug, Clone, FromPyObject)]
+pub struct FloorEntry {
+    pub floor_id: FloorId,
+    pub name: String,
+    /// How far up the floor is, with 0 being the ground floor
+    pub level: Option<i64>,
+    pub icon: Option<String>,
+    pub aliases: HashSet<String>,
+}
+
+#[derive(Debug)]
+pub struct FloorRegistry(Py<PyAny>);
+
+impl<'py> FromPyObject<'py> for FloorRegistry {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // region: Validation
+        validate_type_by_name(ob, "FloorRegistry")?;
+        // endregion: Validation
+
+        Ok(Self(detach(ob)))
+    }
+}
+
+impl FloorRegistry {
+    pub fn get(py: Python<'_>, home_assistant: &HomeAssistant) -> PyResult<Self> {
+        static FLOOR_REGISTRY_MODULE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+        let floor_registry_module = FLOOR_REGISTRY_MODULE
+            .get_or_try_init(|| {
+                Result::<_, PyErr>::Ok(py.import("homeassistant.helpers.floor_registry")?.unbind())
+            })?
+            .bind(py);
+
+        let floor_registry = floor_registry_module.call_method1("async_get", (home_assistant,))?;
+        floor_registry.extract()
+    }
+
+    pub fn floor(&self, py: Python<'_>, floor_id: FloorId) -> PyResult<Option<FloorEntry>> {
+        let floor = self.0.call_method1(py, "async_get_floor", (floor_id,))?;
+        floor.extract(py)
+    }
+
+    pub fn floors(&self, py: Python<'_>) -> PyResult<Vec<FloorEntry>> {
+        let floors = self.0.call_method0(py, "async_list_floors")?;
+        floors
+            .bind(py)
+            .try_iter()?
+            .map(|floor| floor?.extract())
+            .collect()
+    }
+
+    /// Floors being created, updated, removed or reordered
+    #[allow(clippy::type_complexity)]
+    pub fn updates(
+        py: Python<'_>,
+        home_assistant: &HomeAssistant,
+    ) -> PyResult<(
+        Emitter<Arc<floor_registry_updated::Event<Py<PyAny>>>>,
+        JoinHandle<PyResult<()>>,
+    )> {
+        let bus = home_assistant.bus(py)?;
+
+        Ok(bus.listen(py, floor_registry_updated::EVENT_TYPE))
+    }
+}
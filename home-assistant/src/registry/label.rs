@@ -0,0 +1,101 @@
+use std::{convert::Infallible, str::FromStr, sync::Arc};
+
+use emitter_and_signal::emitter::Emitter;
+use once_cell::sync::OnceCell;
+use pyo3::{prelude::*, types::PyString};
+use python_utils::{detach, validate_type_by_name};
+use tokio::task::JoinHandle;
+
+use super::super::{
+    event::specific::label_registry_updated, home_assistant::HomeAssistant, slug::Slug,
+};
+
+pub use super::super::slug::SlugParsingError as LabelIdParsingError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::FromStr)]
+pub struct LabelId(pub Slug);
+
+impl<'py> FromPyObject<'py> for LabelId {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract()?;
+        let parsed = LabelId::from_str(s)?;
+
+        Ok(parsed)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for LabelId {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let s = self.to_string();
+        s.into_pyobject(py)
+    }
+}
+
+#[derive(Debug, Clone, FromPyObject)]
+pub struct LabelEntry {
+    pub label_id: LabelId,
+    pub name: String,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct LabelRegistry(Py<PyAny>);
+
+impl<'py> FromPyObject<'py> for LabelRegistry {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // region: Validation
+        validate_type_by_name(ob, "LabelRegistry")?;
+        // endregion: Validation
+
+        Ok(Self(detach(ob)))
+    }
+}
+
+impl LabelRegistry {
+    pub fn get(py: Python<'_>, home_assistant: &HomeAssistant) -> PyResult<Self> {
+        static LABEL_REGISTRY_MODULE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+        let label_registry_module = LABEL_REGISTRY_MODULE
+            .get_or_try_init(|| {
+                Result::<_, PyErr>::Ok(py.import("homeassistant.helpers.label_registry")?.unbind())
+            })?
+            .bind(py);
+
+        let label_registry = label_registry_module.call_method1("async_get", (home_assistant,))?;
+        label_registry.extract()
+    }
+
+    pub fn label(&self, py: Python<'_>, label_id: LabelId) -> PyResult<Option<LabelEntry>> {
+        let label = self.0.call_method1(py, "async_get_label", (label_id,))?;
+        label.extract(py)
+    }
+
+    pub fn labels(&self, py: Python<'_>) -> PyResult<Vec<LabelEntry>> {
+        let labels = self.0.call_method0(py, "async_list_labels")?;
+        labels
+            .bind(py)
+            .try_iter()?
+            .map(|label| label?.extract())
+            .collect()
+    }
+
+    /// Labels being created, updated or removed
+    #[allow(clippy::type_complexity)]
+    pub fn updates(
+        py: Python<'_>,
+        home_assistant: &HomeAssistant,
+    ) -> PyResult<(
+        Emitter<Arc<label_registry_updated::Event<Py<PyAny>>>>,
+        JoinHandle<PyResult<()>>,
+    )> {
+        let bus = home_assistant.bus(py)?;
+
+        Ok(bus.listen(py, label_registry_updated::EVENT_TYPE))
+    }
+}
@@ -0,0 +1,142 @@
+//! Home Assistant's registries of areas, floors, labels, devices and entities, which make it possible to
+//! target rooms instead of lists of entity IDs that break when something is renamed
+
+use std::{collections::HashMap, str::FromStr};
+
+use area::{AreaEntry, AreaId, AreaRegistry};
+use device::{DeviceEntry, DeviceId, DeviceRegistry};
+use entity::{EntityEntry, EntityRegistry};
+use floor::{FloorEntry, FloorId, FloorRegistry};
+use label::{LabelEntry, LabelId, LabelRegistry};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use strum::EnumString;
+
+use super::{domain::Domain, entity_id::EntityId, home_assistant::HomeAssistant};
+
+pub mod area;
+pub mod device;
+pub mod entity;
+pub mod floor;
+pub mod label;
+
+/// What happened to the entry a registry updated event is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Action {
+    Create,
+    Update,
+    Remove,
+    /// Only for areas and floors, which don't say which entry moved
+    Reorder,
+}
+
+impl<'py> FromPyObject<'py> for Action {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let action = Action::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(action)
+    }
+}
+
+/// Every registry as it was when loaded, for questions that involve more than one of them
+#[derive(Debug, Clone)]
+pub struct Registries {
+    pub areas: HashMap<AreaId, AreaEntry>,
+    pub floors: HashMap<FloorId, FloorEntry>,
+    pub labels: HashMap<LabelId, LabelEntry>,
+    pub devices: HashMap<DeviceId, DeviceEntry>,
+    pub entities: HashMap<EntityId, EntityEntry>,
+}
+
+impl Registries {
+    pub fn load(py: Python<'_>, home_assistant: &HomeAssistant) -> PyResult<Self> {
+        let areas = AreaRegistry::get(py, home_assistant)?
+            .areas(py)?
+            .into_iter()
+            .map(|area| (area.id.clone(), area))
+            .collect();
+        let floors = FloorRegistry::get(py, home_assistant)?
+            .floors(py)?
+            .into_iter()
+            .map(|floor| (floor.floor_id.clone(), floor))
+            .collect();
+        let labels = LabelRegistry::get(py, home_assistant)?
+            .labels(py)?
+            .into_iter()
+            .map(|label| (label.label_id.clone(), label))
+            .collect();
+        let devices = DeviceRegistry::get(py, home_assistant)?
+            .devices(py)?
+            .into_iter()
+            .map(|device| (device.id.clone(), device))
+            .collect();
+        let entities = EntityRegistry::get(py, home_assistant)?
+            .entities(py)?
+            .into_iter()
+            .map(|entity| (entity.entity_id.clone(), entity))
+            .collect();
+
+        Ok(Self {
+            areas,
+            floors,
+            labels,
+            devices,
+            entities,
+        })
+    }
+
+    pub fn device_of(&self, entity_id: &EntityId) -> Option<&DeviceEntry> {
+        let device_id = self.entities.get(entity_id)?.device_id.as_ref()?;
+        self.devices.get(device_id)
+    }
+
+    /// The area the entity is in itself, or else the area its device is in
+    pub fn area_of(&self, entity_id: &EntityId) -> Option<&AreaEntry> {
+        let entity = self.entities.get(entity_id)?;
+        self.areas.get(self.area_id_of(entity)?)
+    }
+
+    fn area_id_of<'a>(&'a self, entity: &'a EntityEntry) -> Option<&'a AreaId> {
+        match &entity.area_id {
+            Some(area_id) => Some(area_id),
+            None => {
+                let device_id = entity.device_id.as_ref()?;
+                self.devices.get(device_id)?.area_id.as_ref()
+            }
+        }
+    }
+
+    /// Entities in the area, whether directly or through their device, optionally only those of one domain
+    pub fn entities_in_area<'a>(
+        &'a self,
+        area_id: &'a AreaId,
+        domain: Option<&'a Domain>,
+    ) -> impl Iterator<Item = &'a EntityEntry> + 'a {
+        self.entities.values().filter(move |entity| {
+            let EntityId(entity_domain, _) = &entity.entity_id;
+
+            domain.is_none_or(|domain| domain == entity_domain)
+                && self.area_id_of(entity) == Some(area_id)
+        })
+    }
+
+    pub fn areas_on_floor<'a>(
+        &'a self,
+        floor_id: &'a FloorId,
+    ) -> impl Iterator<Item = &'a AreaEntry> + 'a {
+        self.areas
+            .values()
+            .filter(move |area| area.floor_id.as_ref() == Some(floor_id))
+    }
+
+    pub fn entities_with_label<'a>(
+        &'a self,
+        label_id: &'a LabelId,
+    ) -> impl Iterator<Item = &'a EntityEntry> + 'a {
+        self.entities
+            .values()
+            .filter(move |entity| entity.labels.contains(label_id))
+    }
+}
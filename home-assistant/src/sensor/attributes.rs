@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use arbitrary_value::map::Map;
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+use strum::EnumString;
+
+use crate::attributes::{extra, item};
+
+/// How the values of a sensor relate to each other over time, which is what long-term statistics go by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum StateClass {
+    Measurement,
+    MeasurementAngle,
+    Total,
+    TotalIncreasing,
+}
+
+impl<'py> FromPyObject<'py> for StateClass {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let state_class =
+            StateClass::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(state_class)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SensorAttributes {
+    pub friendly_name: Option<String>,
+    pub unit_of_measurement: Option<String>,
+    /// Left as a string, since integrations keep adding new ones
+    pub device_class: Option<String>,
+    pub state_class: Option<StateClass>,
+
+    /// Every attribute not listed above, kept as-is
+    pub extra: Map,
+}
+
+const KNOWN_ATTRIBUTES: &[&str] = &[
+    "friendly_name",
+    "unit_of_measurement",
+    "device_class",
+    "state_class",
+];
+
+impl<'py> FromPyObject<'py> for SensorAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        let extra = extra(dict, KNOWN_ATTRIBUTES)?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            unit_of_measurement: item(dict, "unit_of_measurement")?,
+            device_class: item(dict, "device_class")?,
+            state_class: item(dict, "state_class")?,
+            extra,
+        })
+    }
+}
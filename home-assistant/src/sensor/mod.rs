@@ -0,0 +1,194 @@
+use std::{future::Future, marker::PhantomData};
+
+use attributes::{SensorAttributes, StateClass};
+use emitter_and_signal::signal::{JoinError, Signal};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+use tokio::select;
+
+use crate::state::{ErrorState, HomeAssistantState, UnexpectedState};
+
+use super::{
+    domain::Domain, entity_id::EntityId, home_assistant::HomeAssistant, object_id::ObjectId,
+    state_object::StateObject,
+};
+
+pub mod attributes;
+mod quantity;
+
+pub use quantity::SensorQuantity;
+
+/// Numeric sensors report their state as a number, and anything else ends up as [`HomeAssistantState::UnexpectedErr`]
+pub type SensorStateObject = StateObject<HomeAssistantState<f64>, SensorAttributes, Py<PyAny>>;
+
+/// A numeric `sensor.*` entity, read as a [`uom`] quantity like `uom::si::f64::ThermodynamicTemperature`,
+/// so that it can be compared in whatever unit is convenient no matter which unit the sensor reports in
+#[derive(Debug)]
+pub struct HomeAssistantSensor<Q> {
+    pub home_assistant: HomeAssistant,
+    pub object_id: ObjectId,
+    quantity: PhantomData<fn() -> Q>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Reading<Q> {
+    pub quantity: Q,
+    pub device_class: Option<String>,
+    pub state_class: Option<StateClass>,
+}
+
+#[derive(Debug, Clone, Snafu)]
+pub enum ReadingError {
+    Error {
+        state: ErrorState,
+    },
+    #[snafu(display("the state {state:?} is not a number"))]
+    NotANumber {
+        state: UnexpectedState,
+    },
+    #[snafu(display("the sensor doesn't report a unit of measurement"))]
+    MissingUnit,
+    #[snafu(display("{unit:?} is not a unit of {quantity}"))]
+    UnitMismatch {
+        unit: String,
+        quantity: &'static str,
+    },
+}
+
+impl<Q: SensorQuantity> Reading<Q> {
+    pub fn from_state_object(state_object: &SensorStateObject) -> Result<Self, ReadingError> {
+        let value = match &state_object.state {
+            HomeAssistantState::Ok(value) => *value,
+            HomeAssistantState::Err(state) => {
+                return Err(ReadingError::Error {
+                    state: state.clone(),
+                })
+            }
+            HomeAssistantState::UnexpectedErr(state) => {
+                return Err(ReadingError::NotANumber {
+                    state: state.clone(),
+                })
+            }
+        };
+
+        let attributes = &state_object.attributes;
+        let unit = attributes
+            .unit_of_measurement
+            .as_deref()
+            .ok_or(ReadingError::MissingUnit)?;
+        let quantity = Q::from_unit(value, unit).ok_or_else(|| ReadingError::UnitMismatch {
+            unit: unit.to_owned(),
+            quantity: Q::NAME,
+        })?;
+
+        Ok(Self {
+            quantity,
+            device_class: attributes.device_class.clone(),
+            state_class: attributes.state_class,
+        })
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum GetStateObjectError {
+    PythonError { source: PyErr },
+    EntityMissing,
+}
+
+#[derive(Debug, Snafu)]
+pub enum GetReadingError {
+    GetStateObjectError { source: GetStateObjectError },
+    ReadingError { source: ReadingError },
+}
+
+/// `None` while the entity doesn't exist
+pub type ReadingSignal<Q> = Signal<Option<Result<Reading<Q>, ReadingError>>>;
+
+impl<Q> HomeAssistantSensor<Q> {
+    pub fn new(home_assistant: HomeAssistant, object_id: ObjectId) -> Self {
+        Self {
+            home_assistant,
+            object_id,
+            quantity: PhantomData,
+        }
+    }
+
+    fn entity_id(&self) -> EntityId {
+        EntityId(Domain::Sensor, self.object_id.clone())
+    }
+
+    fn get_state_object(&self) -> Result<SensorStateObject, GetStateObjectError> {
+        Python::with_gil(|py| {
+            let states = self.home_assistant.states(py).context(PythonSnafu)?;
+            let entity_id = self.entity_id();
+            let state_object = states
+                .get(py, entity_id)
+                .context(PythonSnafu)?
+                .ok_or(GetStateObjectError::EntityMissing)?;
+
+            Ok(state_object)
+        })
+    }
+}
+
+impl<Q: SensorQuantity> HomeAssistantSensor<Q> {
+    pub fn get_reading(&self) -> Result<Reading<Q>, GetReadingError> {
+        let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
+
+        Reading::from_state_object(&state_object).context(ReadingSnafu)
+    }
+}
+
+impl<Q: SensorQuantity + Clone + Send + Sync + 'static> HomeAssistantSensor<Q> {
+    /// Follow the reading as it changes.
+    ///
+    /// The signal only updates while the returned future is being polled.
+    pub fn signal(
+        &self,
+    ) -> PyResult<(
+        ReadingSignal<Q>,
+        impl Future<Output = Result<(), JoinError>>,
+    )> {
+        let (current, (store, store_task)) = Python::with_gil(|py| {
+            let current: Option<SensorStateObject> =
+                self.home_assistant.states(py)?.get(py, self.entity_id())?;
+            let store = SensorStateObject::store(py, &self.home_assistant, self.entity_id())?;
+
+            PyResult::Ok((current, store))
+        })?;
+
+        let initial = current.map(|state_object| Reading::from_state_object(&state_object));
+
+        let (signal, task) = Signal::new(initial, |mut publisher_stream| async move {
+            while let Some(publisher) = publisher_stream.wait().await {
+                let Ok(mut subscription) = store.subscribe() else {
+                    return;
+                };
+
+                loop {
+                    publisher.publish(
+                        subscription
+                            .get()
+                            .map(|state_object| Reading::from_state_object(&state_object)),
+                    );
+
+                    select! {
+                        biased;
+                        _ = publisher.all_unsubscribed() => break,
+                        changed = subscription.changed() => if changed.is_err() {
+                            return;
+                        },
+                    }
+                }
+            }
+        });
+
+        let task = async move {
+            let (store_result, result) = tokio::join!(store_task, task);
+            store_result?;
+            result
+        };
+
+        Ok((signal, task))
+    }
+}
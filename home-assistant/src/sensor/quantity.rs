@@ -0,0 +1,167 @@
+use uom::si::{
+    electric_current, electric_potential, energy, frequency, length, mass, mass_concentration,
+    power, pressure, ratio, thermodynamic_temperature, time, velocity, volume,
+};
+
+/// A quantity a sensor can measure, and the units Home Assistant reports it in
+pub trait SensorQuantity: Sized {
+    /// For error messages
+    const NAME: &'static str;
+
+    /// `None` when the unit isn't one this quantity can be measured in
+    fn from_unit(value: f64, unit: &str) -> Option<Self>;
+}
+
+macro_rules! sensor_quantity {
+    ($quantity:ident, $name:literal, $module:ident { $($unit:literal => $uom_unit:ident),* $(,)? }) => {
+        impl SensorQuantity for uom::si::f64::$quantity {
+            const NAME: &'static str = $name;
+
+            fn from_unit(value: f64, unit: &str) -> Option<Self> {
+                match unit {
+                    $($unit => Some(Self::new::<$module::$uom_unit>(value)),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+sensor_quantity!(ThermodynamicTemperature, "temperature", thermodynamic_temperature {
+    "°C" => degree_celsius,
+    "°F" => degree_fahrenheit,
+    "K" => kelvin,
+});
+
+sensor_quantity!(Ratio, "ratio", ratio {
+    "%" => percent,
+});
+
+sensor_quantity!(Power, "power", power {
+    "mW" => milliwatt,
+    "W" => watt,
+    "kW" => kilowatt,
+    "MW" => megawatt,
+});
+
+sensor_quantity!(Energy, "energy", energy {
+    "Wh" => watt_hour,
+    "kWh" => kilowatt_hour,
+    "MWh" => megawatt_hour,
+    "J" => joule,
+    "kJ" => kilojoule,
+    "MJ" => megajoule,
+});
+
+sensor_quantity!(Pressure, "pressure", pressure {
+    "Pa" => pascal,
+    "hPa" => hectopascal,
+    "kPa" => kilopascal,
+    "bar" => bar,
+    "mbar" => millibar,
+    "psi" => pound_force_per_square_inch,
+    "inHg" => inch_of_mercury,
+    "mmHg" => millimeter_of_mercury,
+});
+
+sensor_quantity!(ElectricPotential, "voltage", electric_potential {
+    "mV" => millivolt,
+    "V" => volt,
+});
+
+sensor_quantity!(ElectricCurrent, "current", electric_current {
+    "mA" => milliampere,
+    "A" => ampere,
+});
+
+sensor_quantity!(Length, "distance", length {
+    "mm" => millimeter,
+    "cm" => centimeter,
+    "m" => meter,
+    "km" => kilometer,
+    "in" => inch,
+    "ft" => foot,
+    "yd" => yard,
+    "mi" => mile,
+});
+
+sensor_quantity!(Velocity, "speed", velocity {
+    "m/s" => meter_per_second,
+    "km/h" => kilometer_per_hour,
+    "mph" => mile_per_hour,
+    "kn" => knot,
+    "ft/s" => foot_per_second,
+});
+
+sensor_quantity!(Frequency, "frequency", frequency {
+    "Hz" => hertz,
+    "kHz" => kilohertz,
+    "MHz" => megahertz,
+    "GHz" => gigahertz,
+});
+
+sensor_quantity!(Time, "duration", time {
+    "ms" => millisecond,
+    "s" => second,
+    "min" => minute,
+    "h" => hour,
+    "d" => day,
+});
+
+sensor_quantity!(Volume, "volume", volume {
+    "mL" => milliliter,
+    "L" => liter,
+    "m³" => cubic_meter,
+    "gal" => gallon,
+    "ft³" => cubic_foot,
+});
+
+sensor_quantity!(MassConcentration, "concentration", mass_concentration {
+    // Home Assistant writes this with the Greek letter mu, but integrations may use the micro sign
+    "μg/m³" => microgram_per_cubic_meter,
+    "µg/m³" => microgram_per_cubic_meter,
+    "mg/m³" => milligram_per_cubic_meter,
+});
+
+sensor_quantity!(Mass, "weight", mass {
+    "mg" => milligram,
+    "g" => gram,
+    "kg" => kilogram,
+    "oz" => ounce,
+    "lb" => pound,
+});
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{
+        f64::{MassConcentration, ThermodynamicTemperature},
+        mass_concentration::microgram_per_cubic_meter,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    use super::SensorQuantity;
+
+    #[test]
+    fn reads_micrograms_spelled_with_either_mu() {
+        // the Greek mu and the micro sign, escaped since they look the same
+        for unit in ["\u{3bc}g/m³", "\u{b5}g/m³"] {
+            let concentration = MassConcentration::from_unit(12.5, unit)
+                .unwrap_or_else(|| panic!("{unit:?} isn't read as micrograms"));
+
+            assert!((concentration.get::<microgram_per_cubic_meter>() - 12.5).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn reads_temperatures_in_their_own_unit() {
+        let temperature = ThermodynamicTemperature::from_unit(21.0, "°C").unwrap();
+
+        assert!((temperature.get::<degree_celsius>() - 21.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rejects_units_of_another_quantity() {
+        assert!(MassConcentration::from_unit(12.5, "°C").is_none());
+        assert!(ThermodynamicTemperature::from_unit(21.0, "µg/m³").is_none());
+    }
+}
@@ -0,0 +1,6 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+//! Services of the `homeassistant` domain
+
+pub mod restart;
+pub mod update_entity;
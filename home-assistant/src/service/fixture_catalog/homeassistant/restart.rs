@@ -0,0 +1,58 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+use std::str::FromStr;
+
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, supports_response::SupportsResponse,
+    IntoServiceCall,
+};
+
+/// Restart
+///
+/// Restarts Home Assistant.
+///
+/// Calls `homeassistant.restart`
+#[derive(Debug, Clone, Default)]
+pub struct Restart {}
+
+impl Restart {
+    pub const SUPPORTS_RESPONSE: SupportsResponse = SupportsResponse::None;
+
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Only what was given is sent, since Home Assistant rejects `None` for most fields
+#[derive(Debug, Clone)]
+pub struct RestartServiceData {}
+
+impl<'py> IntoPyObject<'py> for RestartServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let Self {} = self;
+
+        let dict = PyDict::new(py);
+
+        Ok(dict)
+    }
+}
+
+impl IntoServiceCall for Restart {
+    type ServiceData = RestartServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("homeassistant").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("restart").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {} = self;
+        let service_data = RestartServiceData {};
+
+        (service_domain, service_id, service_data)
+    }
+}
@@ -0,0 +1,113 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+use std::str::FromStr;
+
+use arbitrary_value::Arbitrary;
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
+
+use crate::{
+    entity_id::EntityId,
+    registry::area::AreaId,
+    service::{
+        service_domain::ServiceDomain, service_id::ServiceId, supports_response::SupportsResponse,
+        IntoServiceCall,
+    },
+};
+
+/// Calls `homeassistant.update_entity`
+#[derive(Debug, Clone)]
+pub struct UpdateEntity {
+    pub area: Option<AreaId>,
+    pub entity_id: Vec<EntityId>,
+    pub min_only: Option<f64>,
+    pub object: Option<Arbitrary>,
+    pub on: bool,
+}
+
+impl UpdateEntity {
+    pub const SUPPORTS_RESPONSE: SupportsResponse = SupportsResponse::Optional;
+
+    pub fn new(entity_id: Vec<EntityId>, on: bool) -> Self {
+        Self {
+            area: None,
+            entity_id,
+            min_only: None,
+            object: None,
+            on,
+        }
+    }
+}
+
+/// Only what was given is sent, since Home Assistant rejects `None` for most fields
+#[derive(Debug, Clone)]
+pub struct UpdateEntityServiceData {
+    area: Option<AreaId>,
+    entity_id: Vec<EntityId>,
+    min_only: Option<f64>,
+    object: Option<Arbitrary>,
+    on: bool,
+}
+
+impl<'py> IntoPyObject<'py> for UpdateEntityServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let Self {
+            area,
+            entity_id,
+            min_only,
+            object,
+            on,
+        } = self;
+
+        let dict = PyDict::new(py);
+
+        if let Some(area) = area {
+            dict.set_item("area", area)?;
+        }
+        dict.set_item("entity_id", entity_id)?;
+        if let Some(min_only) = min_only {
+            if min_only < 1.0 {
+                return Err(PyValueError::new_err(format!(
+                    "`min_only` has to be at least 1, but was {}",
+                    min_only
+                )));
+            }
+            dict.set_item("min_only", min_only)?;
+        }
+        if let Some(object) = object {
+            dict.set_item("object", object)?;
+        }
+        dict.set_item("on", on)?;
+
+        Ok(dict)
+    }
+}
+
+impl IntoServiceCall for UpdateEntity {
+    type ServiceData = UpdateEntityServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("homeassistant").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("update_entity").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            area,
+            entity_id,
+            min_only,
+            object,
+            on,
+        } = self;
+        let service_data = UpdateEntityServiceData {
+            area,
+            entity_id,
+            min_only,
+            object,
+            on,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
@@ -0,0 +1,178 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+use std::{convert::Infallible, str::FromStr};
+
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyString},
+};
+
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, service_target::ServiceTarget,
+    supports_response::SupportsResponse, IntoServiceCall,
+};
+
+/// The options for `flash`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnOnFlash {
+    Long,
+    Short,
+}
+
+impl TurnOnFlash {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for TurnOnFlash {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.as_str().into_pyobject(py)
+    }
+}
+
+/// Turn on
+///
+/// Turns on one or more lights and adjusts their properties,
+/// even when they are turned on already.
+///
+/// Calls `light.turn_on`
+#[derive(Debug, Clone)]
+pub struct TurnOn {
+    pub target: ServiceTarget,
+    pub brightness: Option<f64>,
+    pub color_temp_kelvin: Option<f64>,
+    pub effect: Option<String>,
+    pub flash: Option<TurnOnFlash>,
+    /// Color
+    ///
+    /// For example `[255, 100, 100]`
+    pub rgb_color: Option<(u8, u8, u8)>,
+    /// Transition: Duration it takes to get to next state.
+    pub transition: Option<f64>,
+}
+
+impl TurnOn {
+    pub const SUPPORTS_RESPONSE: SupportsResponse = SupportsResponse::None;
+
+    pub fn new(target: impl Into<ServiceTarget>) -> Self {
+        Self {
+            target: target.into(),
+            brightness: None,
+            color_temp_kelvin: None,
+            effect: None,
+            flash: None,
+            rgb_color: None,
+            transition: None,
+        }
+    }
+}
+
+/// Only what was given is sent, since Home Assistant rejects `None` for most fields
+#[derive(Debug, Clone)]
+pub struct TurnOnServiceData {
+    target: ServiceTarget,
+    brightness: Option<f64>,
+    color_temp_kelvin: Option<f64>,
+    effect: Option<String>,
+    flash: Option<TurnOnFlash>,
+    rgb_color: Option<(u8, u8, u8)>,
+    transition: Option<f64>,
+}
+
+impl<'py> IntoPyObject<'py> for TurnOnServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let Self {
+            target,
+            brightness,
+            color_temp_kelvin,
+            effect,
+            flash,
+            rgb_color,
+            transition,
+        } = self;
+
+        let dict = target.into_pyobject(py)?;
+
+        if let Some(brightness) = brightness {
+            if !(0.0..=255.0).contains(&brightness) {
+                return Err(PyValueError::new_err(format!(
+                    "`brightness` has to be from 0 to 255, but was {}",
+                    brightness
+                )));
+            }
+            dict.set_item("brightness", brightness)?;
+        }
+        if let Some(color_temp_kelvin) = color_temp_kelvin {
+            if !(2000.0..=6500.0).contains(&color_temp_kelvin) {
+                return Err(PyValueError::new_err(format!(
+                    "`color_temp_kelvin` has to be from 2000 to 6500, but was {}",
+                    color_temp_kelvin
+                )));
+            }
+            dict.set_item("color_temp_kelvin", color_temp_kelvin)?;
+        }
+        if let Some(effect) = effect {
+            dict.set_item("effect", effect)?;
+        }
+        if let Some(flash) = flash {
+            dict.set_item("flash", flash)?;
+        }
+        if let Some(rgb_color) = rgb_color {
+            dict.set_item("rgb_color", rgb_color)?;
+        }
+        if let Some(transition) = transition {
+            if !(0.0..=300.0).contains(&transition) {
+                return Err(PyValueError::new_err(format!(
+                    "`transition` has to be from 0 to 300, but was {}",
+                    transition
+                )));
+            }
+            dict.set_item("transition", transition)?;
+        }
+
+        Ok(dict)
+    }
+}
+
+impl IntoServiceCall for TurnOn {
+    type ServiceData = TurnOnServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("light").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("turn_on").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            target,
+            brightness,
+            color_temp_kelvin,
+            effect,
+            flash,
+            rgb_color,
+            transition,
+        } = self;
+        let service_data = TurnOnServiceData {
+            target,
+            brightness,
+            color_temp_kelvin,
+            effect,
+            flash,
+            rgb_color,
+            transition,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
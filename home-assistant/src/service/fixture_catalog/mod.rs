@@ -0,0 +1,10 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+//! Typed calls for the services Home Assistant describes
+
+pub mod _17track;
+pub mod homeassistant;
+pub mod light;
+pub mod script;
+pub mod timer;
+pub mod weather;
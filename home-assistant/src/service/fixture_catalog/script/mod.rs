@@ -0,0 +1,5 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+//! Services of the `script` domain
+
+pub mod super_;
@@ -0,0 +1,123 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+use std::{convert::Infallible, str::FromStr};
+
+use pyo3::{
+    prelude::*,
+    types::{PyDict, PyString},
+};
+
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, supports_response::SupportsResponse,
+    IntoServiceCall,
+};
+
+/// The options for `crate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuperCrate {
+    OptionSelf,
+    OptionSelf1,
+    Super,
+}
+
+impl SuperCrate {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OptionSelf => "self",
+            Self::OptionSelf1 => "Self",
+            Self::Super => "super",
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for SuperCrate {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.as_str().into_pyobject(py)
+    }
+}
+
+/// Super
+///
+/// A service whose name and fields are all keywords.
+///
+/// Calls `script.super`
+#[derive(Debug, Clone, Default)]
+pub struct Super {
+    pub crate_: Option<SuperCrate>,
+    pub self_: Option<String>,
+    pub type_: Option<bool>,
+}
+
+impl Super {
+    pub const SUPPORTS_RESPONSE: SupportsResponse = SupportsResponse::None;
+
+    pub fn new() -> Self {
+        Self {
+            crate_: None,
+            self_: None,
+            type_: None,
+        }
+    }
+}
+
+/// Only what was given is sent, since Home Assistant rejects `None` for most fields
+#[derive(Debug, Clone)]
+pub struct SuperServiceData {
+    crate_: Option<SuperCrate>,
+    self_: Option<String>,
+    type_: Option<bool>,
+}
+
+impl<'py> IntoPyObject<'py> for SuperServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let Self {
+            crate_,
+            self_,
+            type_,
+        } = self;
+
+        let dict = PyDict::new(py);
+
+        if let Some(crate_) = crate_ {
+            dict.set_item("crate", crate_)?;
+        }
+        if let Some(self_) = self_ {
+            dict.set_item("self", self_)?;
+        }
+        if let Some(type_) = type_ {
+            dict.set_item("type", type_)?;
+        }
+
+        Ok(dict)
+    }
+}
+
+impl IntoServiceCall for Super {
+    type ServiceData = SuperServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("script").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("super").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            crate_,
+            self_,
+            type_,
+        } = self;
+        let service_data = SuperServiceData {
+            crate_,
+            self_,
+            type_,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
@@ -0,0 +1,102 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+use std::{str::FromStr, time::Duration};
+
+use arbitrary_value::Arbitrary;
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, service_target::ServiceTarget,
+    supports_response::SupportsResponse, IntoServiceCall,
+};
+
+/// Calls `timer.start`
+#[derive(Debug, Clone)]
+pub struct Start {
+    pub target: ServiceTarget,
+    pub duration: Option<String>,
+    pub wait: Option<Duration>,
+    pub wait_many: Option<Arbitrary>,
+}
+
+impl Start {
+    pub const SUPPORTS_RESPONSE: SupportsResponse = SupportsResponse::None;
+
+    pub fn new(target: impl Into<ServiceTarget>) -> Self {
+        Self {
+            target: target.into(),
+            duration: None,
+            wait: None,
+            wait_many: None,
+        }
+    }
+}
+
+/// Only what was given is sent, since Home Assistant rejects `None` for most fields
+#[derive(Debug, Clone)]
+pub struct StartServiceData {
+    target: ServiceTarget,
+    duration: Option<String>,
+    wait: Option<Duration>,
+    wait_many: Option<Arbitrary>,
+}
+
+impl<'py> IntoPyObject<'py> for StartServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let Self {
+            target,
+            duration,
+            wait,
+            wait_many,
+        } = self;
+
+        let dict = target.into_pyobject(py)?;
+
+        if let Some(duration) = duration {
+            dict.set_item("duration", duration)?;
+        }
+        if let Some(wait) = wait {
+            dict.set_item("wait", time_period_dict(py, wait)?)?;
+        }
+        if let Some(wait_many) = wait_many {
+            dict.set_item("wait_many", wait_many)?;
+        }
+
+        Ok(dict)
+    }
+}
+
+impl IntoServiceCall for Start {
+    type ServiceData = StartServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("timer").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("start").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self {
+            target,
+            duration,
+            wait,
+            wait_many,
+        } = self;
+        let service_data = StartServiceData {
+            target,
+            duration,
+            wait,
+            wait_many,
+        };
+
+        (service_domain, service_id, service_data)
+    }
+}
+
+/// Home Assistant takes durations as a dictionary, whether or not it also takes them as seconds
+fn time_period_dict(py: Python<'_>, duration: Duration) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("seconds", duration.as_secs_f64())?;
+    Ok(dict)
+}
@@ -0,0 +1,100 @@
+// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it
+
+use std::{convert::Infallible, str::FromStr};
+
+use pyo3::{
+    prelude::*,
+    types::{PyDict, PyString},
+};
+
+use crate::service::{
+    service_domain::ServiceDomain, service_id::ServiceId, service_target::ServiceTarget,
+    supports_response::SupportsResponse, IntoServiceCall,
+};
+
+/// The options for `type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GetForecastsType {
+    Daily,
+    Hourly,
+    TwiceDaily,
+}
+
+impl GetForecastsType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Hourly => "hourly",
+            Self::TwiceDaily => "twice_daily",
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for GetForecastsType {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.as_str().into_pyobject(py)
+    }
+}
+
+/// Get forecasts
+///
+/// Get weather forecasts.
+///
+/// Calls `weather.get_forecasts`
+#[derive(Debug, Clone)]
+pub struct GetForecasts {
+    pub target: ServiceTarget,
+    pub type_: GetForecastsType,
+}
+
+impl GetForecasts {
+    pub const SUPPORTS_RESPONSE: SupportsResponse = SupportsResponse::Only;
+
+    pub fn new(target: impl Into<ServiceTarget>, type_: GetForecastsType) -> Self {
+        Self {
+            target: target.into(),
+            type_,
+        }
+    }
+}
+
+/// Only what was given is sent, since Home Assistant rejects `None` for most fields
+#[derive(Debug, Clone)]
+pub struct GetForecastsServiceData {
+    target: ServiceTarget,
+    type_: GetForecastsType,
+}
+
+impl<'py> IntoPyObject<'py> for GetForecastsServiceData {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let Self { target, type_ } = self;
+
+        let dict = target.into_pyobject(py)?;
+
+        dict.set_item("type", type_)?;
+
+        Ok(dict)
+    }
+}
+
+impl IntoServiceCall for GetForecasts {
+    type ServiceData = GetForecastsServiceData;
+
+    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {
+        let service_domain = ServiceDomain::from_str("weather").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+        let service_id = ServiceId::from_str("get_forecasts").expect("statically written and known to be a valid slug; hoping to get compiler checks instead in the future");
+
+        let Self { target, type_ } = self;
+        let service_data = GetForecastsServiceData { target, type_ };
+
+        (service_domain, service_id, service_data)
+    }
+}
@@ -1,8 +1,14 @@
 use service_domain::ServiceDomain;
 use service_id::ServiceId;
 
+/// Generated from `service-catalog-codegen/fixtures/services.json`, only so that tests check the generated code compiles
+#[cfg(test)]
+pub mod fixture_catalog;
+pub mod service_call;
 pub mod service_domain;
 pub mod service_id;
+pub mod service_target;
+pub mod supports_response;
 
 pub trait IntoServiceCall {
     type ServiceData;
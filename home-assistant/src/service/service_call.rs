@@ -0,0 +1,15 @@
+use pyo3::prelude::*;
+
+use super::{service_domain::ServiceDomain, service_id::ServiceId};
+
+/// A call to one of our services, as handed to the handler given to
+/// [`ServiceRegistry::register`](super::super::service_registry::ServiceRegistry::register)
+#[derive(Debug, FromPyObject)]
+pub struct ServiceCall<Data, Context> {
+    pub domain: ServiceDomain,
+    pub service: ServiceId,
+    pub data: Data,
+    /// In order to prevent cycles, the user must decide to pass [`Py<PyAny>`] for the `Event` type of the context
+    pub context: Context,
+    pub return_response: bool,
+}
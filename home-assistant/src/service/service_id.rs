@@ -1,4 +1,4 @@
-use std::convert::Infallible;
+use std::{convert::Infallible, str::FromStr};
 
 use pyo3::{prelude::*, types::PyString};
 
@@ -9,6 +9,15 @@ pub use super::super::slug::SlugParsingError as ServiceIdParsingError;
 #[derive(Debug, Clone, derive_more::Display, derive_more::FromStr)]
 pub struct ServiceId(pub Slug);
 
+impl<'py> FromPyObject<'py> for ServiceId {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract()?;
+        let parsed = ServiceId::from_str(s)?;
+
+        Ok(parsed)
+    }
+}
+
 impl<'py> IntoPyObject<'py> for ServiceId {
     type Target = PyString;
     type Output = Bound<'py, Self::Target>;
@@ -0,0 +1,172 @@
+use std::fmt::Display;
+
+use pyo3::{prelude::*, types::PyDict};
+
+use super::super::{
+    entity_id::EntityId,
+    registry::{area::AreaId, device::DeviceId, floor::FloorId, label::LabelId},
+};
+
+/// Which entities a service call is for, where a device, area, floor or label stands for every entity in it.
+///
+/// Home Assistant resolves these itself at the time of the call, so targeting an area keeps working
+/// as lights are added to it or renamed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceTarget {
+    pub entity_id: Vec<EntityId>,
+    pub device_id: Vec<DeviceId>,
+    pub area_id: Vec<AreaId>,
+    pub floor_id: Vec<FloorId>,
+    pub label_id: Vec<LabelId>,
+}
+
+impl ServiceTarget {
+    pub fn entity(entity_id: EntityId) -> Self {
+        Self {
+            entity_id: vec![entity_id],
+            ..Default::default()
+        }
+    }
+
+    pub fn device(device_id: DeviceId) -> Self {
+        Self {
+            device_id: vec![device_id],
+            ..Default::default()
+        }
+    }
+
+    pub fn area(area_id: AreaId) -> Self {
+        Self {
+            area_id: vec![area_id],
+            ..Default::default()
+        }
+    }
+
+    pub fn floor(floor_id: FloorId) -> Self {
+        Self {
+            floor_id: vec![floor_id],
+            ..Default::default()
+        }
+    }
+
+    pub fn label(label_id: LabelId) -> Self {
+        Self {
+            label_id: vec![label_id],
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let Self {
+            entity_id,
+            device_id,
+            area_id,
+            floor_id,
+            label_id,
+        } = self;
+
+        entity_id.is_empty()
+            && device_id.is_empty()
+            && area_id.is_empty()
+            && floor_id.is_empty()
+            && label_id.is_empty()
+    }
+
+    /// Every kind of target that was given, under the key Home Assistant takes it by
+    fn keyed(&self) -> Vec<(&'static str, Vec<String>)> {
+        fn strings(ids: &[impl Display]) -> Vec<String> {
+            ids.iter().map(ToString::to_string).collect()
+        }
+
+        let Self {
+            entity_id,
+            device_id,
+            area_id,
+            floor_id,
+            label_id,
+        } = self;
+
+        [
+            ("entity_id", strings(entity_id)),
+            ("device_id", strings(device_id)),
+            ("area_id", strings(area_id)),
+            ("floor_id", strings(floor_id)),
+            ("label_id", strings(label_id)),
+        ]
+        .into_iter()
+        .filter(|(_, ids)| !ids.is_empty())
+        .collect()
+    }
+}
+
+impl From<EntityId> for ServiceTarget {
+    fn from(entity_id: EntityId) -> Self {
+        Self::entity(entity_id)
+    }
+}
+
+/// Only the kinds of target that were given are sent, since service data takes the same keys and
+/// Home Assistant merges the two
+impl<'py> IntoPyObject<'py> for ServiceTarget {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let dict = PyDict::new(py);
+
+        for (key, ids) in self.keyed() {
+            dict.set_item(key, ids)?;
+        }
+
+        Ok(dict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ServiceTarget;
+    use crate::{entity_id::EntityId, registry::area::AreaId};
+
+    fn area(area_id: &str) -> AreaId {
+        AreaId::from_str(area_id).unwrap()
+    }
+
+    #[test]
+    fn an_empty_target_sends_nothing() {
+        assert!(ServiceTarget::default().is_empty());
+        assert!(ServiceTarget::default().keyed().is_empty());
+    }
+
+    #[test]
+    fn only_the_kinds_of_target_given_are_sent() {
+        let target = ServiceTarget {
+            entity_id: vec![EntityId::from_str("switch.fan").unwrap()],
+            ..ServiceTarget::area(area("living_room"))
+        };
+
+        assert!(!target.is_empty());
+        assert_eq!(
+            target.keyed(),
+            [
+                ("entity_id", vec!["switch.fan".to_owned()]),
+                ("area_id", vec!["living_room".to_owned()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn sends_every_id_of_a_kind() {
+        let target = ServiceTarget {
+            area_id: vec![area("kitchen"), area("hallway")],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            target.keyed(),
+            [("area_id", vec!["kitchen".to_owned(), "hallway".to_owned()])]
+        );
+    }
+}
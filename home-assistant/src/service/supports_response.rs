@@ -0,0 +1,35 @@
+use once_cell::sync::OnceCell;
+use pyo3::prelude::*;
+
+/// Whether a service returns a response, which callers then have to ask for with `return_response`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum SupportsResponse {
+    /// The service never returns a response
+    #[default]
+    None,
+    /// The service returns a response when it's asked for one
+    Optional,
+    /// The service must be asked for a response
+    Only,
+}
+
+impl<'py> IntoPyObject<'py> for SupportsResponse {
+    type Target = PyAny;
+
+    type Output = Bound<'py, Self::Target>;
+
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        static HOMEASSISTANT_CORE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+        let homeassistant_core = HOMEASSISTANT_CORE
+            .get_or_try_init(|| Result::<_, PyErr>::Ok(py.import("homeassistant.core")?.unbind()))?
+            .bind(py);
+
+        let supports_response_class = homeassistant_core.getattr("SupportsResponse")?;
+
+        supports_response_class.call1((self.to_string(),))
+    }
+}
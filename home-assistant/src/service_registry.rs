@@ -1,5 +1,17 @@
-use super::{event::context::context::Context, service::IntoServiceCall};
-use pyo3::prelude::*;
+use std::{fmt::Display, future::Future};
+
+use super::{
+    event::context::context::Context,
+    service::{
+        service_call::ServiceCall, service_domain::ServiceDomain, service_id::ServiceId,
+        supports_response::SupportsResponse, IntoServiceCall,
+    },
+};
+use once_cell::sync::OnceCell;
+use pyo3::{
+    prelude::*,
+    types::{PyCFunction, PyDict, PyTuple},
+};
 use python_utils::{detach, validate_type_by_name};
 
 #[derive(Debug)]
@@ -51,4 +63,104 @@ impl ServiceRegistry {
         let service_response = future.await?;
         Python::with_gil(|py| service_response.extract(py))
     }
+
+    /// Expose `handler` as the service `domain.service`, replacing any service already registered under that name.
+    ///
+    /// Requests that `Data` can't be extracted from are rejected with a `ServiceValidationError`,
+    /// and errors returned by `handler` are raised as a `HomeAssistantError`.
+    /// `Response` is only passed back to callers when `supports_response` allows it.
+    pub async fn register<Data, Response, Error, Handler, Fut>(
+        &self,
+        domain: ServiceDomain,
+        service: ServiceId,
+        supports_response: SupportsResponse,
+        handler: Handler,
+    ) -> PyResult<()>
+    where
+        Data: for<'py> FromPyObject<'py> + Send + 'static,
+        Response: for<'py> IntoPyObject<'py> + Send + 'static,
+        Error: Display,
+        Handler: Fn(ServiceCall<Data, Py<PyAny>>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Response, Error>> + Send + 'static,
+    {
+        let future = Python::with_gil::<_, PyResult<_>>(|py| {
+            let handler = move |args: &Bound<'_, PyTuple>,
+                                _kwargs: Option<&Bound<'_, PyDict>>|
+                  -> PyResult<Py<PyAny>> {
+                let py = args.py();
+
+                let (service_call,) = args.extract::<(Bound<'_, PyAny>,)>()?;
+                let service_call = service_call
+                    .extract::<ServiceCall<Data, Py<PyAny>>>()
+                    .map_err(|err| {
+                        home_assistant_error(py, "ServiceValidationError", err.to_string())
+                    })?;
+
+                let future = handler(service_call);
+                let awaitable = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                    future.await.map_err(|err| {
+                        Python::with_gil(|py| {
+                            home_assistant_error(py, "HomeAssistantError", err.to_string())
+                        })
+                    })
+                })?;
+
+                Ok(awaitable.unbind())
+            };
+            let handler = PyCFunction::new_closure(py, None, None, handler)?;
+
+            // `async_register` has to run on Home Assistant's event loop, and it only awaits
+            // service functions that are coroutine functions, so both go through Python
+            static RUST_SERVICE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+            let rust_service = RUST_SERVICE
+                .get_or_try_init(|| {
+                    Result::<_, PyErr>::Ok(
+                        PyModule::from_code(
+                            py,
+                            c"
+async def register(services, domain, service, handler, supports_response):
+    async def service_func(call):
+        return await handler(call)
+
+    services.async_register(
+        domain, service, service_func, supports_response=supports_response
+    )
+",
+                            c"rust_service.py",
+                            c"rust_service",
+                        )?
+                        .unbind(),
+                    )
+                })?
+                .bind(py);
+
+            let args = (self.0.bind(py), domain, service, handler, supports_response);
+            let awaitable = rust_service.call_method1("register", args)?;
+            pyo3_async_runtimes::tokio::into_future(awaitable)
+        })?;
+
+        future.await?;
+
+        Ok(())
+    }
+}
+
+/// Make one of the exceptions in `homeassistant.exceptions`, which Home Assistant shows to the user
+fn home_assistant_error(py: Python<'_>, class_name: &str, message: String) -> PyErr {
+    static HOMEASSISTANT_EXCEPTIONS: OnceCell<Py<PyModule>> = OnceCell::new();
+
+    let error = HOMEASSISTANT_EXCEPTIONS
+        .get_or_try_init(|| Result::<_, PyErr>::Ok(py.import("homeassistant.exceptions")?.unbind()))
+        .and_then(|homeassistant_exceptions| {
+            homeassistant_exceptions
+                .bind(py)
+                .getattr(class_name)?
+                .call1((message,))
+        });
+
+    match error {
+        Ok(error) => PyErr::from_value(error),
+        Err(err) => err,
+    }
 }
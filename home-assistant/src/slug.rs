@@ -4,7 +4,7 @@ use pyo3::{exceptions::PyValueError, PyErr};
 use smol_str::SmolStr;
 use snafu::Snafu;
 
-#[derive(Debug, Clone, derive_more::Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display)]
 pub struct Slug(SmolStr);
 
 #[derive(Debug, Clone, Snafu)]
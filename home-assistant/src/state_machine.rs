@@ -1,5 +1,8 @@
+use super::domain::Domain;
 use super::entity_id::EntityId;
+use super::event::context::context::Context;
 use super::state_object::StateObject;
+use once_cell::sync::OnceCell;
 use pyo3::prelude::*;
 use python_utils::{detach, validate_type_by_name};
 
@@ -16,6 +19,42 @@ impl<'py> FromPyObject<'py> for StateMachine {
     }
 }
 
+/// The `async_` methods have to run on Home Assistant's event loop, so they're called from coroutines
+fn rust_state_machine(py: Python<'_>) -> PyResult<&Bound<'_, PyModule>> {
+    static RUST_STATE_MACHINE: OnceCell<Py<PyModule>> = OnceCell::new();
+
+    let rust_state_machine = RUST_STATE_MACHINE
+        .get_or_try_init(|| {
+            Result::<_, PyErr>::Ok(
+                PyModule::from_code(
+                    py,
+                    c"
+async def set(states, entity_id, new_state, attributes, force_update, context):
+    states.async_set(entity_id, new_state, attributes, force_update, context)
+
+
+async def remove(states, entity_id, context):
+    return states.async_remove(entity_id, context)
+
+
+async def all(states, domain):
+    return states.async_all(domain)
+
+
+async def entity_ids(states, domain):
+    return states.async_entity_ids(domain)
+",
+                    c"rust_state_machine.py",
+                    c"rust_state_machine",
+                )?
+                .unbind(),
+            )
+        })?
+        .bind(py);
+
+    Ok(rust_state_machine)
+}
+
 impl StateMachine {
     pub fn get<
         'py,
@@ -31,4 +70,80 @@ impl StateMachine {
         let state = self.0.call_method1(py, "get", args)?;
         state.extract(py)
     }
+
+    /// The states of every entity in the domain
+    pub async fn all<
+        State: for<'py> FromPyObject<'py>,
+        Attributes: for<'py> FromPyObject<'py>,
+        ContextEvent: for<'py> FromPyObject<'py>,
+    >(
+        &self,
+        domain: Domain,
+    ) -> PyResult<Vec<StateObject<State, Attributes, ContextEvent>>> {
+        let future = Python::with_gil::<_, PyResult<_>>(|py| {
+            let args = (self.0.bind(py), domain.to_string());
+            let awaitable = rust_state_machine(py)?.call_method1("all", args)?;
+            pyo3_async_runtimes::tokio::into_future(awaitable)
+        })?;
+
+        let states = future.await?;
+        Python::with_gil(|py| states.extract(py))
+    }
+
+    /// The IDs of every entity in the domain
+    pub async fn entity_ids(&self, domain: Domain) -> PyResult<Vec<EntityId>> {
+        let future = Python::with_gil::<_, PyResult<_>>(|py| {
+            let args = (self.0.bind(py), domain.to_string());
+            let awaitable = rust_state_machine(py)?.call_method1("entity_ids", args)?;
+            pyo3_async_runtimes::tokio::into_future(awaitable)
+        })?;
+
+        let entity_ids = future.await?;
+        Python::with_gil(|py| entity_ids.extract(py))
+    }
+
+    /// Set the state of an entity, creating it if it doesn't exist yet.
+    ///
+    /// Home Assistant only registers a change when the state or attributes differ, unless `force_update` is set.
+    pub async fn set<Attributes: for<'py> IntoPyObject<'py>, Event: for<'py> IntoPyObject<'py>>(
+        &self,
+        entity_id: EntityId,
+        new_state: String,
+        attributes: Attributes,
+        force_update: bool,
+        context: Option<Context<Event>>,
+    ) -> PyResult<()> {
+        let future = Python::with_gil::<_, PyResult<_>>(|py| {
+            let args = (
+                self.0.bind(py),
+                entity_id,
+                new_state,
+                attributes,
+                force_update,
+                context,
+            );
+            let awaitable = rust_state_machine(py)?.call_method1("set", args)?;
+            pyo3_async_runtimes::tokio::into_future(awaitable)
+        })?;
+
+        future.await?;
+
+        Ok(())
+    }
+
+    /// Remove an entity, returning whether it existed
+    pub async fn remove<Event: for<'py> IntoPyObject<'py>>(
+        &self,
+        entity_id: EntityId,
+        context: Option<Context<Event>>,
+    ) -> PyResult<bool> {
+        let future = Python::with_gil::<_, PyResult<_>>(|py| {
+            let args = (self.0.bind(py), entity_id, context);
+            let awaitable = rust_state_machine(py)?.call_method1("remove", args)?;
+            pyo3_async_runtimes::tokio::into_future(awaitable)
+        })?;
+
+        let removed = future.await?;
+        Python::with_gil(|py| removed.extract(py))
+    }
 }
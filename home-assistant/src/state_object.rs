@@ -1,10 +1,12 @@
 use super::{
+    domain::Domain,
     event::{context::context::Context, specific::state_changed},
     home_assistant::HomeAssistant,
+    state_machine::StateMachine,
 };
 use crate::entity_id::EntityId;
 use chrono::{DateTime, Utc};
-use emitter_and_signal::signal::Signal;
+use emitter_and_signal::signal::{JoinError, Signal};
 use once_cell::sync::OnceCell;
 use pyo3::{
     prelude::*,
@@ -13,6 +15,10 @@ use pyo3::{
 use std::{future::Future, sync::Arc};
 use tokio::{select, sync::mpsc};
 
+/// The state objects of every entity in a domain, keyed by their entity IDs
+pub type DomainStore<State, Attributes, ContextEvent> =
+    Signal<im::HashMap<EntityId, Arc<StateObject<State, Attributes, ContextEvent>>>>;
+
 #[derive(Debug, FromPyObject)]
 pub struct StateObject<State, Attributes, ContextEvent> {
     pub entity_id: EntityId,
@@ -148,4 +154,128 @@ impl<
 
         Ok((store, task))
     }
+
+    /// Like [`StateObject::store`], but for every entity in a domain, kept up to date from `state_changed` events.
+    ///
+    /// Entities whose state can't be extracted are left out.
+    #[allow(clippy::type_complexity)]
+    pub async fn store_domain(
+        home_assistant: &HomeAssistant,
+        domain: Domain,
+    ) -> PyResult<(
+        DomainStore<State, Attributes, ContextEvent>,
+        impl Future<Output = Result<(), JoinError>>,
+    )> {
+        let (state_machine, bus) = Python::with_gil::<_, PyResult<_>>(|py| {
+            Ok((home_assistant.states(py)?, home_assistant.bus(py)?))
+        })?;
+        let current = Self::domain_snapshot(&state_machine, &domain).await?;
+
+        let (store, task) = Signal::new(current, |mut publisher_stream| async move {
+            while let Some(publisher) = publisher_stream.wait().await {
+                let (change_sender, mut change_receiver) = mpsc::unbounded_channel();
+
+                // listening before taking the snapshot means no change can fall in between,
+                // and changes from before the snapshot only get applied again
+                let listener_domain = domain.clone();
+                let listener =
+                    move |args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>| {
+                        let Ok((event,)) = args.extract::<(Bound<'_, PyAny>,)>() else {
+                            return;
+                        };
+                        let Ok(data) = event.getattr("data") else {
+                            return;
+                        };
+                        let Ok(entity_id) = data
+                            .get_item("entity_id")
+                            .and_then(|entity_id| entity_id.extract::<EntityId>())
+                        else {
+                            return;
+                        };
+                        if entity_id.0 != listener_domain {
+                            return;
+                        }
+
+                        let new_state = data
+                            .get_item("new_state")
+                            .and_then(|new_state| new_state.extract::<Option<Self>>());
+                        #[cfg(feature = "tracing")]
+                        if let Err(err) = &new_state {
+                            tracing::warn!(?err, %entity_id, "leaving out a state that couldn't be extracted");
+                        }
+
+                        let _ = change_sender.send((entity_id, new_state.ok().flatten()));
+                    };
+                let listener = match bus.add_listener(state_changed::EVENT_TYPE, listener).await {
+                    Ok(listener) => listener,
+                    Err(_err) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::error!(?_err, %domain, "couldn't start tracking the domain");
+                        continue;
+                    }
+                };
+                let snapshot = match Self::domain_snapshot(&state_machine, &domain).await {
+                    Ok(snapshot) => snapshot,
+                    Err(_err) => {
+                        // otherwise Home Assistant would keep calling it for nothing
+                        let _res = listener.remove().await;
+
+                        #[cfg(feature = "tracing")]
+                        tracing::error!(?_err, ?_res, %domain, "couldn't start tracking the domain");
+                        continue;
+                    }
+                };
+                publisher.publish(snapshot);
+
+                loop {
+                    select! {
+                        biased;
+                        _ = publisher.all_unsubscribed() => {
+                            let _res = listener.remove().await;
+
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(?_res, %domain, "stopped tracking the domain");
+                            break;
+                        }
+                        change = change_receiver.recv() => {
+                            let Some((entity_id, new_state)) = change else {
+                                break;
+                            };
+
+                            publisher.publish_with(|states| {
+                                match new_state {
+                                    Some(new_state) => {
+                                        states.insert(entity_id, Arc::new(new_state));
+                                        true
+                                    }
+                                    None => states.remove(&entity_id).is_some(),
+                                }
+                            });
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok((store, task))
+    }
+
+    async fn domain_snapshot(
+        state_machine: &StateMachine,
+        domain: &Domain,
+    ) -> PyResult<im::HashMap<EntityId, Arc<Self>>> {
+        let entity_ids = state_machine.entity_ids(domain.clone()).await?;
+
+        let snapshot = Python::with_gil(|py| {
+            entity_ids
+                .into_iter()
+                .filter_map(|entity_id| {
+                    let state_object = state_machine.get(py, entity_id.clone()).ok()??;
+                    Some((entity_id, Arc::new(state_object)))
+                })
+                .collect()
+        });
+
+        Ok(snapshot)
+    }
 }
@@ -0,0 +1,31 @@
+use arbitrary_value::map::Map;
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::attributes::{extra, item};
+
+#[derive(Debug, Clone)]
+pub struct SwitchAttributes {
+    pub friendly_name: Option<String>,
+    pub device_class: Option<String>,
+    pub icon: Option<String>,
+
+    /// Every attribute not listed above, kept as-is
+    pub extra: Map,
+}
+
+const KNOWN_ATTRIBUTES: &[&str] = &["friendly_name", "device_class", "icon"];
+
+impl<'py> FromPyObject<'py> for SwitchAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        let extra = extra(dict, KNOWN_ATTRIBUTES)?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            device_class: item(dict, "device_class")?,
+            icon: item(dict, "icon")?,
+            extra,
+        })
+    }
+}
@@ -0,0 +1,64 @@
+use std::{future::Future, sync::Arc};
+
+use attributes::SwitchAttributes;
+use emitter_and_signal::signal::{JoinError, Signal};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+use state::SwitchState;
+
+use crate::state::HomeAssistantState;
+
+use super::{
+    domain::Domain, entity_id::EntityId, home_assistant::HomeAssistant, object_id::ObjectId,
+    state_object::StateObject,
+};
+
+pub mod attributes;
+mod protocol;
+pub mod service;
+pub mod state;
+
+pub type SwitchStateObject =
+    StateObject<HomeAssistantState<SwitchState>, SwitchAttributes, Py<PyAny>>;
+
+pub type SwitchStore = Signal<Option<Arc<SwitchStateObject>>>;
+
+#[derive(Debug)]
+pub struct HomeAssistantSwitch {
+    pub home_assistant: HomeAssistant,
+    pub object_id: ObjectId,
+}
+
+impl HomeAssistantSwitch {
+    fn entity_id(&self) -> EntityId {
+        EntityId(Domain::Switch, self.object_id.clone())
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum GetStateObjectError {
+    PythonError { source: PyErr },
+    EntityMissing,
+}
+
+impl HomeAssistantSwitch {
+    fn get_state_object(&self) -> Result<SwitchStateObject, GetStateObjectError> {
+        Python::with_gil(|py| {
+            let states = self.home_assistant.states(py).context(PythonSnafu)?;
+            let entity_id = self.entity_id();
+            let state_object = states
+                .get(py, entity_id)
+                .context(PythonSnafu)?
+                .ok_or(GetStateObjectError::EntityMissing)?;
+
+            Ok(state_object)
+        })
+    }
+
+    /// Follow the state as it changes, which is `None` while the entity doesn't exist.
+    ///
+    /// The signal only updates while the returned future is being polled.
+    pub fn store(&self) -> PyResult<(SwitchStore, impl Future<Output = Result<(), JoinError>>)> {
+        Python::with_gil(|py| SwitchStateObject::store(py, &self.home_assistant, self.entity_id()))
+    }
+}
@@ -0,0 +1,69 @@
+use super::service::{toggle::Toggle, turn_off::TurnOff, turn_on::TurnOn};
+use super::{GetStateObjectError, HomeAssistantSwitch};
+use crate::state::{ErrorState, HomeAssistantState, UnexpectedState};
+use protocol::on_off::{GetState, SetState, State};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+
+#[derive(Debug, Snafu)]
+pub enum GetStateError {
+    GetStateObjectError { source: GetStateObjectError },
+    Error { state: ErrorState },
+    UnexpectedError { state: UnexpectedState },
+}
+
+impl GetState for HomeAssistantSwitch {
+    type Error = GetStateError;
+
+    async fn get_state(&self) -> Result<State, Self::Error> {
+        let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
+        let state = state_object.state;
+
+        match state {
+            HomeAssistantState::Ok(state) => Ok(state.into()),
+            HomeAssistantState::Err(error_state) => {
+                Err(GetStateError::Error { state: error_state })
+            }
+            HomeAssistantState::UnexpectedErr(state) => {
+                Err(GetStateError::UnexpectedError { state })
+            }
+        }
+    }
+}
+
+impl SetState for HomeAssistantSwitch {
+    type Error = PyErr;
+
+    async fn set_state(&mut self, state: State) -> Result<(), Self::Error> {
+        let entity_id = self.entity_id();
+
+        match state {
+            State::Off => {
+                self.home_assistant
+                    .call_service_without_response(TurnOff {
+                        target: entity_id.into(),
+                    })
+                    .await
+            }
+            State::On => {
+                self.home_assistant
+                    .call_service_without_response(TurnOn {
+                        target: entity_id.into(),
+                    })
+                    .await
+            }
+        }
+    }
+}
+
+impl protocol::on_off::Toggle for HomeAssistantSwitch {
+    type Error = PyErr;
+
+    async fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Toggle {
+                target: self.entity_id().into(),
+            })
+            .await
+    }
+}
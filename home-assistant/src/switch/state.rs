@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use strum::EnumString;
+
+#[derive(Debug, Clone, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum SwitchState {
+    On,
+    Off,
+}
+
+impl<'py> FromPyObject<'py> for SwitchState {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let state =
+            SwitchState::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+impl From<SwitchState> for protocol::on_off::State {
+    fn from(state: SwitchState) -> Self {
+        match state {
+            SwitchState::On => protocol::on_off::State::On,
+            SwitchState::Off => protocol::on_off::State::Off,
+        }
+    }
+}
+
+impl From<protocol::on_off::State> for SwitchState {
+    fn from(state: protocol::on_off::State) -> Self {
+        match state {
+            protocol::on_off::State::On => SwitchState::On,
+            protocol::on_off::State::Off => SwitchState::Off,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use pyo3::{prelude::*, types::PyDict};
+
+use crate::attributes::item;
+
+#[derive(Debug, Clone)]
+pub struct VacuumAttributes {
+    pub friendly_name: Option<String>,
+    pub supported_features: Option<u32>,
+    pub battery_level: Option<u8>,
+    pub battery_icon: Option<String>,
+    pub fan_speed: Option<String>,
+    pub fan_speed_list: Option<Vec<String>>,
+    /// A more detailed, integration-specific description of what the vacuum is doing
+    pub status: Option<String>,
+}
+
+impl<'py> FromPyObject<'py> for VacuumAttributes {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+
+        Ok(Self {
+            friendly_name: item(dict, "friendly_name")?,
+            supported_features: item(dict, "supported_features")?,
+            battery_level: item(dict, "battery_level")?,
+            battery_icon: item(dict, "battery_icon")?,
+            fan_speed: item(dict, "fan_speed")?,
+            fan_speed_list: item(dict, "fan_speed_list")?,
+            status: item(dict, "status")?,
+        })
+    }
+}
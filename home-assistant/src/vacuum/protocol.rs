@@ -0,0 +1,125 @@
+use super::service::{
+    clean_spot::CleanSpot, locate::Locate, pause::Pause, return_to_base::ReturnToBase, start::Start,
+};
+use super::{GetStateObjectError, HomeAssistantVacuum};
+use crate::state::{ErrorState, HomeAssistantState, UnexpectedState};
+use protocol::vacuum::{Activity, BatteryLevel, GetActivity, GetBatteryLevel, ReturnToDock};
+use pyo3::prelude::*;
+use snafu::{ResultExt, Snafu};
+
+#[derive(Debug, Snafu)]
+pub enum GetActivityError {
+    GetStateObjectError { source: GetStateObjectError },
+    Error { state: ErrorState },
+    UnexpectedError { state: UnexpectedState },
+}
+
+impl GetActivity for HomeAssistantVacuum {
+    type Error = GetActivityError;
+
+    async fn get_activity(&self) -> Result<Activity, Self::Error> {
+        let state_object = self.get_state_object().context(GetStateObjectSnafu)?;
+        let state = state_object.state;
+
+        match state {
+            HomeAssistantState::Ok(vacuum_state) => Ok(vacuum_state.into()),
+            HomeAssistantState::Err(error_state) => {
+                Err(GetActivityError::Error { state: error_state })
+            }
+            HomeAssistantState::UnexpectedErr(state) => {
+                Err(GetActivityError::UnexpectedError { state })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(module)]
+pub enum GetBatteryLevelError {
+    GetStateObjectError {
+        source: GetStateObjectError,
+    },
+    #[snafu(display("the vacuum doesn't report its battery level"))]
+    Unreported,
+    #[snafu(display("{battery_level} is not a valid battery level"))]
+    OutOfRange {
+        battery_level: u8,
+    },
+}
+
+impl GetBatteryLevel for HomeAssistantVacuum {
+    type Error = GetBatteryLevelError;
+
+    async fn get_battery_level(&self) -> Result<BatteryLevel, Self::Error> {
+        let state_object = self
+            .get_state_object()
+            .context(get_battery_level_error::GetStateObjectSnafu)?;
+
+        let battery_level = state_object
+            .attributes
+            .battery_level
+            .ok_or(GetBatteryLevelError::Unreported)?;
+
+        BatteryLevel::new(battery_level).ok_or(GetBatteryLevelError::OutOfRange { battery_level })
+    }
+}
+
+impl protocol::vacuum::Start for HomeAssistantVacuum {
+    type Error = PyErr;
+
+    async fn start(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Start {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl protocol::vacuum::Pause for HomeAssistantVacuum {
+    type Error = PyErr;
+
+    async fn pause(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Pause {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl ReturnToDock for HomeAssistantVacuum {
+    type Error = PyErr;
+
+    async fn return_to_dock(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(ReturnToBase {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl protocol::vacuum::Locate for HomeAssistantVacuum {
+    type Error = PyErr;
+
+    async fn locate(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(Locate {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
+
+impl protocol::vacuum::CleanSpot for HomeAssistantVacuum {
+    type Error = PyErr;
+
+    async fn clean_spot(&mut self) -> Result<(), Self::Error> {
+        self.home_assistant
+            .call_service_without_response(CleanSpot {
+                entity_id: self.entity_id(),
+            })
+            .await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod clean_spot;
+pub mod locate;
+pub mod pause;
+pub mod return_to_base;
+pub mod start;
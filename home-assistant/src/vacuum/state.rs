@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use strum::EnumString;
+
+#[derive(Debug, Clone, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum VacuumState {
+    Cleaning,
+    Docked,
+    Idle,
+    Paused,
+    Returning,
+    Error,
+}
+
+impl<'py> FromPyObject<'py> for VacuumState {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let s = ob.extract::<String>()?;
+
+        let state =
+            VacuumState::from_str(&s).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+impl From<VacuumState> for protocol::vacuum::Activity {
+    fn from(vacuum_state: VacuumState) -> Self {
+        match vacuum_state {
+            VacuumState::Cleaning => protocol::vacuum::Activity::Cleaning,
+            VacuumState::Docked => protocol::vacuum::Activity::Docked,
+            VacuumState::Idle => protocol::vacuum::Activity::Idle,
+            VacuumState::Paused => protocol::vacuum::Activity::Paused,
+            VacuumState::Returning => protocol::vacuum::Activity::Returning,
+            VacuumState::Error => protocol::vacuum::Activity::Error,
+        }
+    }
+}
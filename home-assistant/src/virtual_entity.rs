@@ -0,0 +1,113 @@
+use std::{fmt::Display, future::Future, pin::pin};
+
+use emitter_and_signal::signal::Signal;
+use pyo3::{prelude::*, types::PyDict, IntoPyObjectExt};
+use tokio::select;
+
+use super::{entity_id::EntityId, event::context::context::Context, home_assistant::HomeAssistant};
+
+/// An entity whose state is computed in Rust, like an occupancy estimate, published into the state machine.
+///
+/// Home Assistant only knows it through the state machine, so it has no entity registry entry
+/// and is gone after a restart until it's published again.
+#[derive(Debug)]
+pub struct VirtualEntity<State, Attributes> {
+    pub entity_id: EntityId,
+    pub friendly_name: Option<String>,
+    pub unit_of_measurement: Option<String>,
+    pub device_class: Option<String>,
+    /// `Attributes` has to become a Python `dict`, like [`arbitrary_value::map::Map`] does
+    pub signal: Signal<(State, Attributes)>,
+}
+
+impl<State, Attributes> VirtualEntity<State, Attributes> {
+    pub fn new(entity_id: EntityId, signal: Signal<(State, Attributes)>) -> Self {
+        Self {
+            entity_id,
+            friendly_name: None,
+            unit_of_measurement: None,
+            device_class: None,
+            signal,
+        }
+    }
+}
+
+impl<State, Attributes> VirtualEntity<State, Attributes>
+where
+    State: Display + Clone,
+    Attributes: for<'py> IntoPyObject<'py> + Clone,
+{
+    /// Keep the entity in sync with the signal until the signal's producer exits or `shutdown` resolves,
+    /// and then remove it again.
+    pub async fn run(
+        self,
+        home_assistant: &HomeAssistant,
+        shutdown: impl Future<Output = ()>,
+    ) -> PyResult<()> {
+        let Self {
+            entity_id,
+            friendly_name,
+            unit_of_measurement,
+            device_class,
+            signal,
+        } = self;
+
+        let states = Python::with_gil(|py| home_assistant.states(py))?;
+
+        // holding on to the signal would keep the subscription from ever seeing the producer exit
+        let subscription = signal.subscribe();
+        drop(signal);
+
+        let synced = async {
+            let Ok(mut subscription) = subscription else {
+                return Ok(());
+            };
+            let mut shutdown = pin!(shutdown);
+
+            loop {
+                let (state, attributes) = subscription.get();
+
+                let attributes = Python::with_gil::<_, PyResult<_>>(|py| {
+                    let attributes = attributes.into_bound_py_any(py)?;
+                    let attributes = attributes.downcast::<PyDict>()?.copy()?;
+
+                    let known = [
+                        ("friendly_name", &friendly_name),
+                        ("unit_of_measurement", &unit_of_measurement),
+                        ("device_class", &device_class),
+                    ];
+                    for (key, value) in known {
+                        if let Some(value) = value {
+                            attributes.set_item(key, value)?;
+                        }
+                    }
+
+                    Ok(attributes.unbind())
+                })?;
+
+                states
+                    .set(
+                        entity_id.clone(),
+                        state.to_string(),
+                        attributes,
+                        false,
+                        None::<Context<Py<PyAny>>>,
+                    )
+                    .await?;
+
+                select! {
+                    biased;
+                    _ = &mut shutdown => return Ok(()),
+                    changed = subscription.changed() => if changed.is_err() {
+                        return Ok(());
+                    },
+                }
+            }
+        };
+        let synced: PyResult<()> = synced.await;
+
+        let removed = states.remove(entity_id, None::<Context<Py<PyAny>>>).await;
+
+        synced.and(removed.map(|_| ()))
+    }
+}
@@ -0,0 +1,11 @@
+use std::{error::Error, future::Future};
+
+use deranged::RangedU8;
+
+/// Charge remaining as a percentage of a full battery
+pub type BatteryLevel = RangedU8<0, 100>;
+
+pub trait GetBatteryLevel {
+    type Error: Error;
+    fn get_battery_level(&self) -> impl Future<Output = Result<BatteryLevel, Self::Error>> + Send;
+}
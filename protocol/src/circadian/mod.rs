@@ -0,0 +1,196 @@
+//! Light that follows the sun, like Home Assistant's adaptive lighting:
+//! cool and bright around noon, warm and dim around midnight.
+
+use std::{error::Error, future::Future, time::Duration};
+
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
+use emitter_and_signal::signal::{JoinError, Signal, Subscription};
+use snafu::{ResultExt, Snafu};
+use tokio::time::MissedTickBehavior;
+
+use crate::light::{
+    Brightness, ColorSetting, Kelvin, LightSetting, State, TurnToBrightness, TurnToTemperature,
+};
+
+mod sun;
+
+pub use sun::{Horizon, Location, SunTimes};
+
+/// A time of day during which the lights should stay at a fixed, restful setting.
+/// It may span midnight, like 22:00 to 07:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SleepPeriod {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub temperature: Kelvin,
+    pub brightness: Brightness,
+}
+
+impl SleepPeriod {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            self.start <= time || time < self.end
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Circadian {
+    pub location: Location,
+    pub min_temperature: Kelvin,
+    pub max_temperature: Kelvin,
+    pub min_brightness: Brightness,
+    pub max_brightness: Brightness,
+    pub sleep: Option<SleepPeriod>,
+    /// Passed along in every setting, so that lights drift rather than jump from one minute to the next
+    pub transition: Option<Duration>,
+}
+
+impl Circadian {
+    /// How high the sun is in its daily cycle, from 1 at solar noon through 0 at sunrise and sunset
+    /// to -1 at solar midnight
+    pub fn sun_position(&self, at: DateTime<Utc>) -> f64 {
+        let sun_times = self.location.sun_times_at(at);
+        let at = at.with_timezone(&self.location.timezone);
+        let noon = sun_times.solar_noon;
+
+        let (rise, set) = match sun_times.daylight {
+            Horizon::Crossed { rise, set } => (rise, set),
+            Horizon::AlwaysAbove => return 1.0,
+            Horizon::AlwaysBelow => return -1.0,
+        };
+
+        let parabola = |peak: DateTime<_>, edge: DateTime<_>| {
+            let from_peak = (at - peak).num_milliseconds() as f64;
+            let width = (edge - peak).num_milliseconds() as f64;
+            (1.0 - (from_peak / width).powi(2)).clamp(0.0, 1.0)
+        };
+
+        if at < rise {
+            -parabola(noon - TimeDelta::hours(12), rise)
+        } else if at <= set {
+            parabola(noon, if at < noon { rise } else { set })
+        } else {
+            -parabola(noon + TimeDelta::hours(12), set)
+        }
+    }
+
+    /// Temperature rises from the minimum at sunrise to the maximum at noon and back,
+    /// and stays at the minimum all night.
+    /// Brightness stays at the maximum all day,
+    /// and dips from it at sunset to the minimum at midnight and back.
+    pub fn setting_at(&self, at: DateTime<Utc>) -> LightSetting {
+        let local_time = at.with_timezone(&self.location.timezone).time();
+
+        let (temperature, brightness) = match self.sleep {
+            Some(sleep) if sleep.contains(local_time) => (sleep.temperature, sleep.brightness),
+            _ => {
+                let position = self.sun_position(at);
+
+                let temperature = lerp(
+                    self.min_temperature.get(),
+                    self.max_temperature.get(),
+                    position.max(0.0),
+                );
+                let brightness = lerp(
+                    self.min_brightness.get(),
+                    self.max_brightness.get(),
+                    1.0 + position.min(0.0),
+                );
+
+                (
+                    Kelvin::new_saturating(temperature.round() as u16),
+                    Brightness::new_saturating(brightness.round() as u8),
+                )
+            }
+        };
+
+        LightSetting {
+            state: State::On,
+            color: Some(ColorSetting::Temperature(temperature)),
+            brightness: Some(brightness),
+            transition: self.transition,
+        }
+    }
+
+    /// The setting for right now, updated every minute for as long as anyone is subscribed
+    pub fn signal(
+        self,
+    ) -> (
+        Signal<LightSetting>,
+        impl Future<Output = Result<(), JoinError>>,
+    ) {
+        Signal::new(
+            self.setting_at(Utc::now()),
+            move |mut publisher_stream| async move {
+                while let Some(publisher) = publisher_stream.wait().await {
+                    let mut interval = tokio::time::interval(Duration::from_secs(60));
+                    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+                    loop {
+                        tokio::select! {
+                            _ = publisher.all_unsubscribed() => break,
+                            _ = interval.tick() => {
+                                let setting = self.setting_at(Utc::now());
+                                publisher.publish_with(|current| {
+                                    let changed = *current != setting;
+                                    *current = setting;
+                                    changed
+                                });
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+}
+
+fn lerp(min: impl Into<f64>, max: impl Into<f64>, amount: f64) -> f64 {
+    let (min, max) = (min.into(), max.into());
+    min + (max - min) * amount
+}
+
+#[derive(Debug, Clone, Snafu)]
+pub enum FollowError<TemperatureError: Error + 'static, BrightnessError: Error + 'static> {
+    TemperatureError { source: TemperatureError },
+    BrightnessError { source: BrightnessError },
+}
+
+/// Keep a light at the temperature and brightness of every setting from a [`Circadian::signal`],
+/// until the signal's producer exits.
+///
+/// The setting's state is left alone, so that this doesn't turn back on a light someone turned off.
+pub async fn follow<Light: TurnToTemperature + TurnToBrightness>(
+    light: &mut Light,
+    mut subscription: Subscription<LightSetting>,
+) -> Result<(), FollowError<<Light as TurnToTemperature>::Error, <Light as TurnToBrightness>::Error>>
+where
+    <Light as TurnToTemperature>::Error: 'static,
+    <Light as TurnToBrightness>::Error: 'static,
+{
+    loop {
+        let setting = subscription.get();
+
+        if let Some(ColorSetting::Temperature(temperature)) = setting.color {
+            light
+                .turn_to_temperature(temperature)
+                .await
+                .context(TemperatureSnafu)?;
+        }
+        if let Some(brightness) = setting.brightness {
+            light
+                .turn_to_brightness(brightness)
+                .await
+                .context(BrightnessSnafu)?;
+        }
+
+        if subscription.changed().await.is_err() {
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::f64::consts::TAU;
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Utc};
+use chrono_tz::Tz;
+
+/// How far below the horizon the center of the sun is at sunrise and sunset,
+/// accounting for refraction and the size of the sun
+const SUNRISE_ZENITH: f64 = 90.833;
+const CIVIL_TWILIGHT_ZENITH: f64 = 96.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Location {
+    /// Degrees, positive to the north
+    pub latitude: f64,
+    /// Degrees, positive to the east
+    pub longitude: f64,
+    pub timezone: Tz,
+}
+
+/// Whether the sun crosses some angle below the horizon on a given day,
+/// which it doesn't do near the poles for parts of the year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumIs)]
+pub enum Horizon {
+    Crossed {
+        rise: DateTime<Tz>,
+        set: DateTime<Tz>,
+    },
+    AlwaysAbove,
+    AlwaysBelow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SunTimes {
+    pub solar_noon: DateTime<Tz>,
+    /// Sunrise and sunset
+    pub daylight: Horizon,
+    /// Dawn and dusk, when the sun is 6° below the horizon
+    pub civil_twilight: Horizon,
+}
+
+impl Location {
+    /// Computed offline with NOAA's approximations, which are accurate to a minute or so
+    /// outside of the polar regions
+    pub fn sun_times(&self, date: NaiveDate) -> SunTimes {
+        // NOAA's approximations work on UTC days, and the sun's noon on some UTC day can fall on
+        // the local day before or after it, where the time zone is far from what the longitude suggests
+        // (like in Kiritimati, which is on UTC+14 at 157° west)
+        let utc_date = [date, date - TimeDelta::days(1), date + TimeDelta::days(1)]
+            .into_iter()
+            .find(|&utc_date| {
+                let (equation_of_time, _) = solar_position(utc_date);
+                self.at_minutes(utc_date, self.solar_noon(equation_of_time))
+                    .date_naive()
+                    == date
+            })
+            .unwrap_or(date);
+
+        let (equation_of_time, declination) = solar_position(utc_date);
+        let solar_noon = self.solar_noon(equation_of_time);
+
+        let horizon = |zenith: f64| {
+            let latitude = self.latitude.to_radians();
+            let cos_hour_angle = zenith.to_radians().cos() / (latitude.cos() * declination.cos())
+                - latitude.tan() * declination.tan();
+
+            if cos_hour_angle > 1.0 {
+                Horizon::AlwaysBelow
+            } else if cos_hour_angle < -1.0 {
+                Horizon::AlwaysAbove
+            } else {
+                let hour_angle = cos_hour_angle.acos().to_degrees();
+
+                Horizon::Crossed {
+                    rise: self.at_minutes(utc_date, solar_noon - 4.0 * hour_angle),
+                    set: self.at_minutes(utc_date, solar_noon + 4.0 * hour_angle),
+                }
+            }
+        };
+
+        SunTimes {
+            solar_noon: self.at_minutes(utc_date, solar_noon),
+            daylight: horizon(SUNRISE_ZENITH),
+            civil_twilight: horizon(CIVIL_TWILIGHT_ZENITH),
+        }
+    }
+
+    pub fn sun_times_at(&self, at: DateTime<Utc>) -> SunTimes {
+        self.sun_times(at.with_timezone(&self.timezone).date_naive())
+    }
+
+    /// Minutes after midnight UTC
+    fn solar_noon(&self, equation_of_time: f64) -> f64 {
+        720.0 - 4.0 * self.longitude - equation_of_time
+    }
+
+    fn at_minutes(&self, utc_date: NaiveDate, minutes: f64) -> DateTime<Tz> {
+        let midnight = utc_date
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight exists")
+            .and_utc();
+        let time = midnight + TimeDelta::milliseconds((minutes * 60_000.0).round() as i64);
+        time.with_timezone(&self.timezone)
+    }
+}
+
+/// The equation of time in minutes and the sun's declination in radians, at noon UTC
+fn solar_position(date: NaiveDate) -> (f64, f64) {
+    let days_in_year = if date.leap_year() { 366.0 } else { 365.0 };
+    let fractional_year = TAU / days_in_year * f64::from(date.ordinal0());
+
+    let (sin1, cos1) = fractional_year.sin_cos();
+    let (sin2, cos2) = (2.0 * fractional_year).sin_cos();
+    let (sin3, cos3) = (3.0 * fractional_year).sin_cos();
+
+    let equation_of_time =
+        229.18 * (0.000075 + 0.001868 * cos1 - 0.032077 * sin1 - 0.014615 * cos2 - 0.040849 * sin2);
+
+    let declination = 0.006918 - 0.399912 * cos1 + 0.070257 * sin1 - 0.006758 * cos2
+        + 0.000907 * sin2
+        - 0.002697 * cos3
+        + 0.00148 * sin3;
+
+    (equation_of_time, declination)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime, Timelike};
+    use chrono_tz::{America, Europe, Pacific, Tz};
+
+    use super::*;
+
+    /// NOAA's approximations against the times published for these places, to within a few minutes
+    fn assert_near(actual: DateTime<Tz>, date: NaiveDate, expected: NaiveTime) {
+        assert_eq!(actual.date_naive(), date, "{actual} is on the wrong day");
+        let difference = (actual.time() - expected).num_seconds().abs();
+        assert!(difference <= 180, "{actual} is too far from {expected}");
+    }
+
+    fn crossed(horizon: Horizon) -> (DateTime<Tz>, DateTime<Tz>) {
+        match horizon {
+            Horizon::Crossed { rise, set } => (rise, set),
+            horizon => panic!("expected the sun to rise and set, but it's {horizon:?}"),
+        }
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn london_midsummer() {
+        let london = Location {
+            latitude: 51.5074,
+            longitude: -0.1278,
+            timezone: Europe::London,
+        };
+        let date = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+
+        let (rise, set) = crossed(london.sun_times(date).daylight);
+        assert_near(rise, date, time(4, 43));
+        assert_near(set, date, time(21, 21));
+    }
+
+    #[test]
+    fn new_york_midwinter() {
+        let new_york = Location {
+            latitude: 40.7128,
+            longitude: -74.006,
+            timezone: America::New_York,
+        };
+        let date = NaiveDate::from_ymd_opt(2026, 12, 21).unwrap();
+
+        let (rise, set) = crossed(new_york.sun_times(date).daylight);
+        assert_near(rise, date, time(7, 17));
+        assert_near(set, date, time(16, 32));
+    }
+
+    #[test]
+    fn kiritimati_stays_on_the_local_day() {
+        let kiritimati = Location {
+            latitude: 1.87,
+            longitude: -157.43,
+            timezone: Pacific::Kiritimati,
+        };
+        let date = NaiveDate::from_ymd_opt(2026, 3, 21).unwrap();
+        let sun_times = kiritimati.sun_times(date);
+
+        assert_eq!(sun_times.solar_noon.date_naive(), date);
+        assert_eq!(sun_times.solar_noon.hour(), 12);
+
+        let (rise, set) = crossed(sun_times.daylight);
+        assert_near(rise, date, time(6, 32));
+        assert_near(set, date, time(18, 39));
+    }
+
+    #[test]
+    fn midnight_sun() {
+        let tromso = Location {
+            latitude: 69.6492,
+            longitude: 18.9553,
+            timezone: Europe::Oslo,
+        };
+
+        let sun_times = tromso.sun_times(NaiveDate::from_ymd_opt(2026, 6, 21).unwrap());
+        assert!(sun_times.daylight.is_always_above());
+
+        let sun_times = tromso.sun_times(NaiveDate::from_ymd_opt(2026, 12, 21).unwrap());
+        assert!(sun_times.daylight.is_always_below());
+    }
+}
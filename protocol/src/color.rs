@@ -0,0 +1,329 @@
+//! Converting between color temperatures and colors,
+//! and fitting colors into what a particular light can actually show
+
+use palette::{white_point::D65, IntoColor, Xyz, Yxy};
+
+use crate::light::{Kelvin, Oklch};
+
+/// A point in the CIE 1931 xy chromaticity diagram
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Chromaticity {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Chromaticity {
+    const D65: Self = Self {
+        x: 0.3127,
+        y: 0.3290,
+    };
+
+    /// Where a color is in the diagram, leaving out how bright it is
+    pub fn of(color: Oklch) -> Self {
+        let xyz: Xyz<D65, f64> = color.into_color();
+        let yxy: Yxy<D65, f64> = xyz.into_color();
+
+        Self { x: yxy.x, y: yxy.y }
+    }
+
+    /// At a luminance of 1
+    fn to_xyz(self) -> [f64; 3] {
+        [self.x / self.y, 1.0, (1.0 - self.x - self.y) / self.y]
+    }
+}
+
+/// The chromaticity of a black body at this temperature,
+/// using Kim et al.'s cubic approximation of the Planckian locus
+pub fn planckian_locus(temperature: Kelvin) -> Chromaticity {
+    let t = f64::from(temperature.get());
+    let (t2, t3) = (t * t, t * t * t);
+
+    let x = if t <= 4000.0 {
+        -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910
+    } else {
+        -3.0258469e9 / t3 + 2.1070379e6 / t2 + 0.2226347e3 / t + 0.240390
+    };
+    let (x2, x3) = (x * x, x * x * x);
+
+    let y = if t <= 2222.0 {
+        -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x3 - 5.87338670 * x2 + 3.75112997 * x - 0.37001483
+    };
+
+    Chromaticity { x, y }
+}
+
+/// The brightest color an sRGB display can show for a black body at this temperature
+pub fn kelvin_to_oklch(temperature: Kelvin) -> Oklch {
+    let rgb = Gamut::SRGB.xyz_to_linear_rgb(planckian_locus(temperature).to_xyz());
+    let max = rgb.into_iter().fold(f64::MIN_POSITIVE, f64::max);
+
+    Gamut::SRGB.from_linear_rgb(rgb.map(|channel| channel / max))
+}
+
+/// The correlated color temperature of a color, using McCamy's approximation.
+///
+/// This is only meaningful for colors that are close to white;
+/// anything else ends up at whichever end of the range is closest.
+pub fn oklch_to_kelvin(color: Oklch) -> Kelvin {
+    let Chromaticity { x, y } = Chromaticity::of(color);
+
+    let n = (x - 0.3320) / (0.1858 - y);
+    let kelvin = 449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33;
+
+    Kelvin::new_saturating(kelvin.clamp(0.0, u16::MAX.into()).round() as u16)
+}
+
+/// The colors a light can make, as the triangle between its red, green and blue primaries
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gamut {
+    pub red: Chromaticity,
+    pub green: Chromaticity,
+    pub blue: Chromaticity,
+}
+
+type Matrix = [[f64; 3]; 3];
+
+const CHROMA_EPSILON: f64 = 0.0001;
+
+impl Gamut {
+    pub const SRGB: Self = Self {
+        red: Chromaticity { x: 0.64, y: 0.33 },
+        green: Chromaticity { x: 0.30, y: 0.60 },
+        blue: Chromaticity { x: 0.15, y: 0.06 },
+    };
+
+    pub const DISPLAY_P3: Self = Self {
+        red: Chromaticity { x: 0.680, y: 0.320 },
+        green: Chromaticity { x: 0.265, y: 0.690 },
+        blue: Chromaticity { x: 0.150, y: 0.060 },
+    };
+
+    /// Philips Hue's first color bulbs and LivingColors lamps, like the LST001 and LLC010
+    pub const HUE_A: Self = Self {
+        red: Chromaticity { x: 0.704, y: 0.296 },
+        green: Chromaticity {
+            x: 0.2151,
+            y: 0.7106,
+        },
+        blue: Chromaticity { x: 0.138, y: 0.08 },
+    };
+
+    /// Philips Hue's older bulbs, like the LCT001 and LCT007
+    pub const HUE_B: Self = Self {
+        red: Chromaticity { x: 0.675, y: 0.322 },
+        green: Chromaticity { x: 0.409, y: 0.518 },
+        blue: Chromaticity { x: 0.167, y: 0.04 },
+    };
+
+    /// Philips Hue's bulbs since the LCT010 and LST002, which covers most of them in use today
+    pub const HUE_C: Self = Self {
+        red: Chromaticity {
+            x: 0.6915,
+            y: 0.3083,
+        },
+        green: Chromaticity { x: 0.17, y: 0.7 },
+        blue: Chromaticity {
+            x: 0.1532,
+            y: 0.0475,
+        },
+    };
+
+    /// With a D65 white point, which is what every light this crate knows of calibrates to
+    fn rgb_to_xyz(&self) -> Matrix {
+        let [r, g, b] = [self.red, self.green, self.blue].map(Chromaticity::to_xyz);
+        let primaries = transpose([r, g, b]);
+
+        let scale = multiply(invert(primaries), Chromaticity::D65.to_xyz());
+
+        primaries.map(|row| [row[0] * scale[0], row[1] * scale[1], row[2] * scale[2]])
+    }
+
+    fn xyz_to_linear_rgb(&self, xyz: [f64; 3]) -> [f64; 3] {
+        multiply(invert(self.rgb_to_xyz()), xyz)
+    }
+
+    /// Linear (not gamma encoded) red, green and blue, which are within 0 to 1 if the light can make the color
+    pub fn to_linear_rgb(&self, color: Oklch) -> [f64; 3] {
+        let xyz: Xyz<D65, f64> = color.into_color();
+        self.xyz_to_linear_rgb(xyz.into_components().into())
+    }
+
+    pub fn from_linear_rgb(&self, rgb: [f64; 3]) -> Oklch {
+        let [x, y, z] = multiply(self.rgb_to_xyz(), rgb);
+        Xyz::<D65, f64>::new(x, y, z).into_color()
+    }
+
+    /// How far up its brightness the light has to be to make the color, from 0 to 1,
+    /// assuming it dims perceptually like a display does (with the sRGB transfer function)
+    pub fn brightness(&self, color: Oklch) -> f64 {
+        let max = self
+            .to_linear_rgb(color)
+            .into_iter()
+            .fold(0.0, f64::max)
+            .min(1.0);
+
+        if max <= 0.0031308 {
+            12.92 * max
+        } else {
+            1.055 * max.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    pub fn contains(&self, color: Oklch) -> bool {
+        self.to_linear_rgb(color)
+            .into_iter()
+            .all(|channel| (-CHROMA_EPSILON..=1.0 + CHROMA_EPSILON).contains(&channel))
+    }
+
+    /// The closest color to the one given that the light can make,
+    /// keeping its lightness and hue exactly and giving up only as much chroma as needed
+    pub fn map(&self, color: Oklch) -> Oklch {
+        if color.l >= 1.0 {
+            return Oklch::new(1.0, 0.0, color.hue);
+        }
+        if color.l <= 0.0 {
+            return Oklch::new(0.0, 0.0, color.hue);
+        }
+        if self.contains(color) {
+            return color;
+        }
+
+        let mut in_gamut = Oklch {
+            chroma: 0.0,
+            ..color
+        };
+        let mut max = color.chroma;
+
+        while max - in_gamut.chroma > CHROMA_EPSILON {
+            let current = Oklch {
+                chroma: (in_gamut.chroma + max) / 2.0,
+                ..color
+            };
+
+            if self.contains(current) {
+                in_gamut = current;
+            } else {
+                max = current.chroma;
+            }
+        }
+
+        in_gamut
+    }
+}
+
+fn transpose(m: Matrix) -> Matrix {
+    [0, 1, 2].map(|row| [0, 1, 2].map(|column| m[column][row]))
+}
+
+fn multiply(m: Matrix, v: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn invert(m: Matrix) -> Matrix {
+    let [[a, b, c], [d, e, f], [g, h, i]] = m;
+
+    let determinant = a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g);
+
+    [
+        [e * i - f * h, c * h - b * i, b * f - c * e],
+        [f * g - d * i, a * i - c * g, c * d - a * f],
+        [d * h - e * g, b * g - a * h, a * e - b * d],
+    ]
+    .map(|row| row.map(|value| value / determinant))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} isn't within {tolerance} of {expected}"
+        );
+    }
+
+    fn hue_difference(a: Oklch, b: Oklch) -> f64 {
+        let difference = (a.hue.into_positive_degrees() - b.hue.into_positive_degrees()).abs();
+        difference.min(360.0 - difference)
+    }
+
+    #[test]
+    fn kelvin_round_trips_through_oklch() {
+        for kelvin in (2000..=10000).step_by(250) {
+            let kelvin = Kelvin::new(kelvin).unwrap();
+            let round_tripped = oklch_to_kelvin(kelvin_to_oklch(kelvin));
+
+            // McCamy's approximation drifts further from the locus the cooler the light gets
+            let tolerance = f64::from(kelvin.get()) * 0.03;
+            assert_close(round_tripped.get().into(), kelvin.get().into(), tolerance);
+        }
+    }
+
+    #[test]
+    fn kelvin_is_white_enough_to_be_in_srgb() {
+        for kelvin in [2700, 4000, 6500] {
+            let color = kelvin_to_oklch(Kelvin::new(kelvin).unwrap());
+            assert!(Gamut::SRGB.contains(color), "{kelvin}K is {color:?}");
+        }
+    }
+
+    #[test]
+    fn linear_rgb_round_trips() {
+        for gamut in [Gamut::SRGB, Gamut::DISPLAY_P3, Gamut::HUE_C] {
+            for rgb in [[1.0, 0.0, 0.0], [0.2, 0.7, 0.1], [0.5, 0.5, 0.5]] {
+                let round_tripped = gamut.to_linear_rgb(gamut.from_linear_rgb(rgb));
+
+                for (actual, expected) in round_tripped.into_iter().zip(rgb) {
+                    assert_close(actual, expected, 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mapping_keeps_colors_already_in_the_gamut() {
+        let color = Gamut::SRGB.from_linear_rgb([0.3, 0.6, 0.2]);
+        assert_eq!(Gamut::SRGB.map(color), color);
+    }
+
+    #[test]
+    fn mapping_only_gives_up_chroma() {
+        let p3_green = Gamut::DISPLAY_P3.from_linear_rgb([0.0, 1.0, 0.0]);
+        assert!(!Gamut::SRGB.contains(p3_green));
+
+        let mapped = Gamut::SRGB.map(p3_green);
+        assert!(Gamut::SRGB.contains(mapped));
+        assert_close(mapped.l, p3_green.l, 1e-12);
+        assert!(hue_difference(mapped, p3_green) < 1e-9);
+        assert!(mapped.chroma < p3_green.chroma);
+
+        assert_eq!(Gamut::SRGB.map(mapped), mapped);
+    }
+
+    #[test]
+    fn mapping_into_a_device_gamut() {
+        // dim enough that only its chromaticity decides whether the bulbs can make it
+        let srgb_green = Gamut::SRGB.from_linear_rgb([0.0, 0.3, 0.0]);
+
+        // Hue's older bulbs can't make as saturated a green as an sRGB display, but its newer ones can
+        assert!(!Gamut::HUE_B.contains(srgb_green));
+        assert!(Gamut::HUE_B.contains(Gamut::HUE_B.map(srgb_green)));
+        assert!(Gamut::HUE_C.contains(srgb_green));
+    }
+
+    #[test]
+    fn brightness_matches_srgb_value() {
+        assert_close(Gamut::SRGB.brightness(Oklch::new(1.0, 0.0, 0.0)), 1.0, 1e-6);
+        assert_close(Gamut::SRGB.brightness(Oklch::new(0.0, 0.0, 0.0)), 0.0, 1e-6);
+
+        let half_red = Gamut::SRGB.from_linear_rgb([0.214, 0.0, 0.0]);
+        assert_close(Gamut::SRGB.brightness(half_red), 0.5, 1e-3);
+    }
+}
@@ -0,0 +1,87 @@
+use std::{error::Error, future::Future};
+
+use deranged::RangedU8;
+
+pub use super::on_off::{
+    GetState, InvertToToggleError, IsOff, IsOn, SetState, State, Toggle, ToggleByInverting,
+    TurnOff, TurnOn,
+};
+
+/// Speed as a percentage of the fan's maximum
+pub type Percentage = RangedU8<0, 100>;
+
+pub trait GetPercentage {
+    type Error: Error;
+    /// Returns `None` when the fan doesn't know or doesn't report its speed
+    fn get_percentage(
+        &self,
+    ) -> impl Future<Output = Result<Option<Percentage>, Self::Error>> + Send;
+}
+
+pub trait SetPercentage {
+    type Error: Error;
+    fn set_percentage(
+        &mut self,
+        percentage: Percentage,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait GetPresetMode {
+    type Error: Error;
+    /// Returns `None` when the fan isn't in any preset mode
+    fn get_preset_mode(&self) -> impl Future<Output = Result<Option<String>, Self::Error>> + Send;
+}
+
+pub trait SetPresetMode {
+    type Error: Error;
+    fn set_preset_mode(
+        &mut self,
+        preset_mode: String,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait GetOscillating {
+    type Error: Error;
+    /// Returns `None` when the fan can't oscillate
+    fn get_oscillating(&self) -> impl Future<Output = Result<Option<bool>, Self::Error>> + Send;
+}
+
+pub trait Oscillate {
+    type Error: Error;
+    fn set_oscillating(
+        &mut self,
+        oscillating: bool,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display, strum::EnumIs,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Direction {
+    Forward,
+    Reverse,
+}
+
+impl Direction {
+    pub const fn invert(self) -> Self {
+        match self {
+            Direction::Forward => Direction::Reverse,
+            Direction::Reverse => Direction::Forward,
+        }
+    }
+}
+
+pub trait GetDirection {
+    type Error: Error;
+    /// Returns `None` when the fan can't change direction
+    fn get_direction(&self) -> impl Future<Output = Result<Option<Direction>, Self::Error>> + Send;
+}
+
+pub trait SetDirection {
+    type Error: Error;
+    fn set_direction(
+        &mut self,
+        direction: Direction,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
@@ -0,0 +1,33 @@
+use std::{error::Error, future::Future};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display, strum::EnumIs,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Activity {
+    Mowing,
+    Docked,
+    Paused,
+    Returning,
+    Error,
+}
+
+pub trait GetActivity {
+    type Error: Error;
+    fn get_activity(&self) -> impl Future<Output = Result<Activity, Self::Error>> + Send;
+}
+
+pub trait Start {
+    type Error: Error;
+    fn start(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait Pause {
+    type Error: Error;
+    fn pause(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait ReturnToDock {
+    type Error: Error;
+    fn return_to_dock(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
@@ -1 +1,12 @@
+pub mod battery;
+#[cfg(feature = "circadian")]
+pub mod circadian;
+pub mod color;
+pub mod fan;
+pub mod lawn_mower;
 pub mod light;
+pub mod media_player;
+pub mod on_off;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod vacuum;
@@ -0,0 +1,298 @@
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use emitter_and_signal::signal::Subscription;
+use tokio::{task::AbortHandle, time::Instant};
+
+use super::{
+    layer::Layer, ApplySetting, Brightness, Capabilities, GetSetting, GetState, Kelvin,
+    LightCapabilities, LightSetting, Oklch, SetState, State, Toggle, TurnToBrightness, TurnToColor,
+    TurnToTemperature,
+};
+
+#[derive(Debug, Default)]
+struct Cache {
+    entry: Option<(State, Instant)>,
+    /// Bumped whenever the state is invalidated or assumed,
+    /// so that a read that was already underway can't store what it read
+    generation: u64,
+}
+
+#[derive(Debug)]
+struct AbortOnDrop(AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Answers [`GetState`] from memory for up to `ttl` after last finding out the state,
+/// which it does both by asking the light and by assuming successful commands took effect
+#[derive(Debug)]
+pub struct Cached<Light> {
+    pub inner: Light,
+    ttl: Duration,
+    cache: Arc<Mutex<Cache>>,
+    invalidation: Option<AbortOnDrop>,
+}
+
+impl<Light> Cached<Light> {
+    pub fn new(inner: Light, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: Arc::default(),
+            invalidation: None,
+        }
+    }
+
+    /// Forget the cached state whenever the subscription changes,
+    /// like when the backend pushes a new state for the light
+    pub fn with_invalidation<T: Send + Sync + 'static>(
+        mut self,
+        mut subscription: Subscription<T>,
+    ) -> Self {
+        let cache = Arc::downgrade(&self.cache);
+
+        let task = tokio::spawn(async move {
+            while subscription.changed().await.is_ok() {
+                let Some(cache) = cache.upgrade() else {
+                    return;
+                };
+                invalidate(&mut lock(&cache));
+            }
+        });
+
+        self.invalidation = Some(AbortOnDrop(task.abort_handle()));
+        self
+    }
+
+    pub fn invalidate(&self) {
+        invalidate(&mut lock(&self.cache));
+    }
+
+    fn fresh(&self) -> Result<State, u64> {
+        let cache = lock(&self.cache);
+
+        match cache.entry {
+            Some((state, at)) if at.elapsed() < self.ttl => Ok(state),
+            _ => Err(cache.generation),
+        }
+    }
+
+    /// Remember what was read, unless the cache has changed since the read started
+    fn store(&self, state: State, generation: u64) {
+        let mut cache = lock(&self.cache);
+
+        if generation == cache.generation {
+            cache.entry = Some((state, Instant::now()));
+        }
+    }
+
+    /// Remember the state a successful command left the light in
+    fn assume(&self, state: State) {
+        let mut cache = lock(&self.cache);
+
+        cache.entry = Some((state, Instant::now()));
+        cache.generation += 1;
+    }
+
+    /// Assume the state if the command succeeded, and otherwise forget it,
+    /// since a command that failed may still have reached the light
+    fn settle<E>(&self, result: Result<(), E>, state: State) -> Result<(), E> {
+        match result {
+            Ok(()) => self.assume(state),
+            Err(_) => self.invalidate(),
+        }
+
+        result
+    }
+}
+
+fn lock(cache: &Mutex<Cache>) -> MutexGuard<'_, Cache> {
+    cache.lock().expect("nothing panics while holding the lock")
+}
+
+fn invalidate(cache: &mut Cache) {
+    cache.entry = None;
+    cache.generation += 1;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CacheLayer {
+    pub ttl: Duration,
+}
+
+impl CacheLayer {
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl }
+    }
+}
+
+impl<Light> Layer<Light> for CacheLayer {
+    type Light = Cached<Light>;
+
+    fn layer(&self, light: Light) -> Self::Light {
+        Cached::new(light, self.ttl)
+    }
+}
+
+impl<Light: GetState + Sync> GetState for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn get_state(&self) -> Result<State, Self::Error> {
+        let generation = match self.fresh() {
+            Ok(state) => return Ok(state),
+            Err(generation) => generation,
+        };
+
+        let state = self.inner.get_state().await?;
+        self.store(state, generation);
+
+        Ok(state)
+    }
+}
+
+impl<Light: SetState + Send + Sync> SetState for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn set_state(&mut self, state: State) -> Result<(), Self::Error> {
+        let result = self.inner.set_state(state).await;
+        self.settle(result, state)
+    }
+}
+
+impl<Light: Toggle + Send + Sync> Toggle for Cached<Light> {
+    type Error = Light::Error;
+
+    /// Only the state that was still fresh can be assumed inverted, otherwise it's forgotten
+    async fn toggle(&mut self) -> Result<(), Self::Error> {
+        let result = self.inner.toggle().await;
+
+        match (&result, self.fresh()) {
+            (Ok(()), Ok(state)) => self.assume(state.invert()),
+            _ => self.invalidate(),
+        }
+
+        result
+    }
+}
+
+impl<Light: TurnToTemperature + Send + Sync> TurnToTemperature for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn turn_to_temperature(&mut self, temperature: Kelvin) -> Result<(), Self::Error> {
+        let result = self.inner.turn_to_temperature(temperature).await;
+        self.settle(result, State::On)
+    }
+}
+
+impl<Light: TurnToColor + Send + Sync> TurnToColor for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn turn_to_color(&mut self, color: Oklch) -> Result<(), Self::Error> {
+        let result = self.inner.turn_to_color(color).await;
+        self.settle(result, State::On)
+    }
+}
+
+impl<Light: TurnToBrightness + Send + Sync> TurnToBrightness for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn turn_to_brightness(&mut self, brightness: Brightness) -> Result<(), Self::Error> {
+        let result = self.inner.turn_to_brightness(brightness).await;
+        self.settle(result, State::On)
+    }
+}
+
+impl<Light: ApplySetting + Send + Sync> ApplySetting for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn apply_setting(&mut self, setting: LightSetting) -> Result<(), Self::Error> {
+        let result = self.inner.apply_setting(setting).await;
+        self.settle(result, setting.state)
+    }
+}
+
+impl<Light: GetSetting + Sync> GetSetting for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn get_setting(&self) -> Result<LightSetting, Self::Error> {
+        let generation = lock(&self.cache).generation;
+
+        let setting = self.inner.get_setting().await?;
+        self.store(setting.state, generation);
+
+        Ok(setting)
+    }
+}
+
+impl<Light: Capabilities + Sync> Capabilities for Cached<Light> {
+    type Error = Light::Error;
+
+    async fn capabilities(&self) -> Result<LightCapabilities, Self::Error> {
+        self.inner.capabilities().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Cached;
+    use crate::{
+        light::{GetState, LightSetting, Oklch, SetState, State, Toggle, TurnToColor},
+        testing::FakeLight,
+    };
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    /// A cache that believes the light is on, while the light has actually been turned off behind its back
+    async fn stale() -> (FakeLight, Cached<FakeLight>) {
+        let fake = FakeLight::new(LightSetting::ON);
+        let cached = Cached::new(fake.clone(), TTL);
+
+        assert_eq!(cached.get_state().await.unwrap(), State::On);
+        fake.clone().set_state(State::Off).await.unwrap();
+        assert_eq!(cached.get_state().await.unwrap(), State::On);
+
+        (fake, cached)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_commands_forget_the_state() {
+        let (fake, mut cached) = stale().await;
+
+        fake.fail_next(1);
+        assert!(cached
+            .turn_to_color(Oklch::new(0.6, 0.15, 30.0))
+            .await
+            .is_err());
+
+        assert_eq!(cached.get_state().await.unwrap(), State::Off);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_toggles_forget_the_state() {
+        let (fake, mut cached) = stale().await;
+
+        fake.fail_next(1);
+        assert!(cached.toggle().await.is_err());
+
+        assert_eq!(cached.get_state().await.unwrap(), State::Off);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn successful_commands_are_assumed() {
+        let fake = FakeLight::default();
+        let mut cached = Cached::new(fake.clone(), TTL);
+
+        cached.set_state(State::On).await.unwrap();
+        fake.fail_next(1);
+
+        assert_eq!(cached.get_state().await.unwrap(), State::On);
+    }
+}
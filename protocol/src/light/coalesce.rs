@@ -0,0 +1,409 @@
+use std::{
+    error::Error,
+    fmt::{self, Debug},
+    future::Future,
+    mem,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use snafu::Snafu;
+use tokio::{sync::oneshot, time::Instant};
+
+use super::{
+    BoxFuture, Brightness, Capabilities, GetState, Kelvin, LightCapabilities, Oklch, SetState,
+    State, TurnToBrightness, TurnToColor, TurnToTemperature,
+};
+
+#[derive(Debug, Clone, Snafu)]
+pub enum CoalesceError {
+    /// The command that was finally sent failed, which may have been a newer one than this caller's
+    #[snafu(display("the command finally sent to the light failed"))]
+    Failed { source: SharedError },
+    #[snafu(display("the task sending commands to the light stopped before reporting back"))]
+    Stopped,
+}
+
+type Outcome = Result<(), CoalesceError>;
+type SharedError = Arc<dyn Error + Send + Sync>;
+
+fn share(error: impl Error + Send + Sync + 'static) -> SharedError {
+    Arc::new(error)
+}
+
+/// A command waiting to be sent, which sends itself to whichever light it's given
+type Command<Light> =
+    Box<dyn for<'a> FnOnce(&'a mut Light) -> BoxFuture<'a, Result<(), SharedError>> + Send>;
+
+/// Everything that hasn't been sent to the light yet, merged into the newest of each kind
+struct Pending<Light> {
+    state: Option<Command<Light>>,
+    /// Color or temperature, whichever came last, since either replaces the other
+    color: Option<Command<Light>>,
+    brightness: Option<Command<Light>>,
+    waiters: Vec<oneshot::Sender<Outcome>>,
+    draining: bool,
+}
+
+impl<Light> Debug for Pending<Light> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pending")
+            .field("state", &self.state.is_some())
+            .field("color", &self.color.is_some())
+            .field("brightness", &self.brightness.is_some())
+            .field("waiters", &self.waiters.len())
+            .field("draining", &self.draining)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    State(State),
+    Color,
+    Brightness,
+}
+
+impl<Light> Pending<Light> {
+    /// Drop whatever the command makes obsolete, keeping in mind that
+    /// every command other than turning off also turns the light on
+    fn merge(&mut self, kind: Kind, command: Command<Light>) {
+        match kind {
+            Kind::State(state) => {
+                if state.is_off() {
+                    self.color = None;
+                    self.brightness = None;
+                }
+                self.state = Some(command);
+            }
+            Kind::Color => {
+                self.state = None;
+                self.color = Some(command);
+            }
+            Kind::Brightness => {
+                self.state = None;
+                self.brightness = Some(command);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Device<Light> {
+    light: Light,
+    last_sent: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct Inner<Light> {
+    device: tokio::sync::Mutex<Device<Light>>,
+    min_interval: Option<Duration>,
+    pending: Mutex<Pending<Light>>,
+}
+
+/// Sends only the newest commands to the light when commands come in faster than it can take them,
+/// like when a slider is dragged.
+///
+/// Commands wait in a single slot per light, where each drops whatever it makes obsolete
+/// (turning off drops everything), and a single task sends what's left in order.
+/// Every caller that was waiting is told how the commands that were finally sent went.
+/// Clones share the same light.
+#[derive(Debug)]
+pub struct Coalesce<Light> {
+    inner: Arc<Inner<Light>>,
+}
+
+impl<Light> Clone for Coalesce<Light> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Light> Coalesce<Light> {
+    /// With a `min_interval`, commands are also sent no more often than that
+    pub fn new(light: Light, min_interval: Option<Duration>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                device: tokio::sync::Mutex::new(Device {
+                    light,
+                    last_sent: None,
+                }),
+                min_interval,
+                pending: Mutex::new(Pending {
+                    state: None,
+                    color: None,
+                    brightness: None,
+                    waiters: Vec::new(),
+                    draining: false,
+                }),
+            }),
+        }
+    }
+}
+
+impl<Light: Send + 'static> Coalesce<Light> {
+    async fn submit(&self, kind: Kind, command: Command<Light>) -> Outcome {
+        let (sender, receiver) = oneshot::channel();
+
+        let start_draining = {
+            let mut pending = self
+                .inner
+                .pending
+                .lock()
+                .expect("nothing panics while holding the lock");
+            pending.merge(kind, command);
+            pending.waiters.push(sender);
+            !mem::replace(&mut pending.draining, true)
+        };
+
+        if start_draining {
+            tokio::spawn(drain(self.inner.clone()));
+        }
+
+        receiver.await.unwrap_or(Err(CoalesceError::Stopped))
+    }
+}
+
+/// Keep sending whatever is pending until nothing is
+async fn drain<Light>(inner: Arc<Inner<Light>>) {
+    loop {
+        let mut device = inner.device.lock().await;
+
+        let (commands, waiters) = {
+            let mut pending = inner
+                .pending
+                .lock()
+                .expect("nothing panics while holding the lock");
+
+            let commands = [
+                pending.state.take(),
+                pending.color.take(),
+                pending.brightness.take(),
+            ];
+            if commands.iter().all(Option::is_none) {
+                pending.draining = false;
+                return;
+            }
+
+            (commands, mem::take(&mut pending.waiters))
+        };
+
+        let mut outcome = Ok(());
+        for command in commands.into_iter().flatten() {
+            if let (Some(min_interval), Some(last_sent)) = (inner.min_interval, device.last_sent) {
+                tokio::time::sleep_until(last_sent + min_interval).await;
+            }
+
+            let result = command(&mut device.light).await;
+            device.last_sent = Some(Instant::now());
+
+            if let Err(source) = result {
+                outcome = Err(CoalesceError::Failed { source });
+                break;
+            }
+        }
+        drop(device);
+
+        for waiter in waiters {
+            let _ = waiter.send(outcome.clone());
+        }
+    }
+}
+
+impl<Light: GetState + Send + Sync> GetState for Coalesce<Light> {
+    type Error = Light::Error;
+
+    async fn get_state(&self) -> Result<State, Self::Error> {
+        self.inner.device.lock().await.light.get_state().await
+    }
+}
+
+impl<Light: Capabilities + Send + Sync> Capabilities for Coalesce<Light> {
+    type Error = Light::Error;
+
+    async fn capabilities(&self) -> Result<LightCapabilities, Self::Error> {
+        self.inner.device.lock().await.light.capabilities().await
+    }
+}
+
+impl<Light> SetState for Coalesce<Light>
+where
+    Light: SetState + Send + 'static,
+    Light::Error: Send + Sync + 'static,
+{
+    type Error = CoalesceError;
+
+    fn set_state(&mut self, state: State) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.submit(
+            Kind::State(state),
+            Box::new(move |light: &mut Light| {
+                Box::pin(async move { light.set_state(state).await.map_err(share) })
+            }),
+        )
+    }
+}
+
+impl<Light> TurnToColor for Coalesce<Light>
+where
+    Light: TurnToColor + Send + 'static,
+    Light::Error: Send + Sync + 'static,
+{
+    type Error = CoalesceError;
+
+    fn turn_to_color(
+        &mut self,
+        color: Oklch,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.submit(
+            Kind::Color,
+            Box::new(move |light: &mut Light| {
+                Box::pin(async move { light.turn_to_color(color).await.map_err(share) })
+            }),
+        )
+    }
+}
+
+impl<Light> TurnToTemperature for Coalesce<Light>
+where
+    Light: TurnToTemperature + Send + 'static,
+    Light::Error: Send + Sync + 'static,
+{
+    type Error = CoalesceError;
+
+    fn turn_to_temperature(
+        &mut self,
+        temperature: Kelvin,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.submit(
+            Kind::Color,
+            Box::new(move |light: &mut Light| {
+                Box::pin(async move { light.turn_to_temperature(temperature).await.map_err(share) })
+            }),
+        )
+    }
+}
+
+impl<Light> TurnToBrightness for Coalesce<Light>
+where
+    Light: TurnToBrightness + Send + 'static,
+    Light::Error: Send + Sync + 'static,
+{
+    type Error = CoalesceError;
+
+    fn turn_to_brightness(
+        &mut self,
+        brightness: Brightness,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.submit(
+            Kind::Brightness,
+            Box::new(move |light: &mut Light| {
+                Box::pin(async move { light.turn_to_brightness(brightness).await.map_err(share) })
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::Future, time::Duration};
+
+    use tokio::task::JoinHandle;
+
+    use super::Coalesce;
+    use crate::{
+        light::{Oklch, SetState, State, TurnToBrightness, TurnToColor},
+        testing::{Command, FakeLight},
+    };
+
+    const LATENCY: Duration = Duration::from_millis(100);
+
+    /// Send a command from its own task, and give it a moment to reach the light or the slot
+    async fn spawn<F>(command: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send,
+    {
+        let handle = tokio::spawn(command);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        handle
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn turning_off_drops_colors_still_waiting() {
+        let fake = FakeLight::default().with_latency(LATENCY);
+        let light = Coalesce::new(fake.clone(), None);
+        let a = Oklch::new(0.6, 0.15, 30.0);
+        let b = Oklch::new(0.7, 0.1, 200.0);
+
+        let mut l = light.clone();
+        let first = spawn(async move { l.turn_to_color(a).await }).await;
+        let mut l = light.clone();
+        let second = spawn(async move { l.turn_to_color(b).await }).await;
+        let mut l = light.clone();
+        let third = spawn(async move { l.set_state(State::Off).await }).await;
+
+        for handle in [first, second, third] {
+            handle.await.unwrap().unwrap();
+        }
+
+        assert_eq!(
+            fake.commands(),
+            [Command::TurnToColor(a), Command::SetState(State::Off)]
+        );
+        assert!(fake.setting().state.is_off());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn commands_after_turning_off_turn_back_on() {
+        let fake = FakeLight::default().with_latency(LATENCY);
+        let light = Coalesce::new(fake.clone(), None);
+        let a = Oklch::new(0.6, 0.15, 30.0);
+        let brightness = 40.try_into().unwrap();
+
+        let mut l = light.clone();
+        let first = spawn(async move { l.turn_to_color(a).await }).await;
+        let mut l = light.clone();
+        let second = spawn(async move { l.set_state(State::Off).await }).await;
+        let mut l = light.clone();
+        let third = spawn(async move { l.turn_to_brightness(brightness).await }).await;
+
+        for handle in [first, second, third] {
+            handle.await.unwrap().unwrap();
+        }
+
+        assert_eq!(
+            fake.commands(),
+            [
+                Command::TurnToColor(a),
+                Command::TurnToBrightness(brightness)
+            ]
+        );
+        assert!(fake.setting().state.is_on());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn only_the_newest_of_a_burst_is_sent() {
+        let fake = FakeLight::default().with_latency(LATENCY);
+        let light = Coalesce::new(fake.clone(), None);
+        let colors = [10.0, 20.0, 30.0, 40.0].map(|hue| Oklch::new(0.6, 0.15, hue));
+
+        let mut handles = Vec::new();
+        for color in colors {
+            let mut l = light.clone();
+            handles.push(spawn(async move { l.turn_to_color(color).await }).await);
+        }
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        assert_eq!(
+            fake.commands(),
+            [
+                Command::TurnToColor(colors[0]),
+                Command::TurnToColor(colors[3])
+            ]
+        );
+    }
+}
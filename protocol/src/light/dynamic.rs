@@ -0,0 +1,445 @@
+use std::{error::Error, future::Future, pin::Pin};
+
+use snafu::Snafu;
+
+use super::{
+    ApplySetting, Brightness, Capabilities, GetSetting, GetState, Kelvin, LightCapabilities,
+    LightSetting, Oklch, SetState, State, Toggle, TurnToBrightness, TurnToColor, TurnToTemperature,
+};
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+#[derive(Debug, Snafu)]
+pub enum DynError {
+    /// The light behind the [`DynLight`] can't do this at all, which its [`Capabilities`] should have said
+    #[snafu(display("the light doesn't support {operation}"))]
+    Unsupported { operation: &'static str },
+    /// Whatever error the light behind the [`DynLight`] had, with its type erased
+    #[snafu(display("the light failed"))]
+    Light {
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl DynError {
+    pub fn new(error: impl Error + Send + Sync + 'static) -> Self {
+        Self::Light {
+            source: Box::new(error),
+        }
+    }
+
+    /// The light's own error, unless it didn't get as far as having one
+    pub fn into_inner(self) -> Option<Box<dyn Error + Send + Sync>> {
+        match self {
+            DynError::Unsupported { .. } => None,
+            DynError::Light { source } => Some(source),
+        }
+    }
+}
+
+/// Box a light's future and erase its error type
+fn erase<'a, T, E: Error + Send + Sync + 'static>(
+    future: impl Future<Output = Result<T, E>> + Send + 'a,
+) -> BoxFuture<'a, Result<T, DynError>> {
+    Box::pin(async move { future.await.map_err(DynError::new) })
+}
+
+fn unsupported<'a, T: Send + 'a>(operation: &'static str) -> BoxFuture<'a, Result<T, DynError>> {
+    Box::pin(std::future::ready(UnsupportedSnafu { operation }.fail()))
+}
+
+/// A dyn-compatible counterpart to the light traits, so that lights of different types
+/// (like from different vendors) can be kept together as `Box<dyn DynLight>`.
+///
+/// Every light that can get and set its state and say what it's capable of is a `DynLight`,
+/// including the wrappers like [`Cached`](super::Cached) and the layers.
+/// Everything else fails with [`DynError::Unsupported`] unless the light is wrapped in an [`ErasedLight`]
+/// that was told which of the other light traits to forward,
+/// and users are expected to check [`DynLight::dyn_capabilities`] before asking for more.
+/// `Box<dyn DynLight>` implements the light traits in turn.
+pub trait DynLight: Send + Sync {
+    fn dyn_get_state(&self) -> BoxFuture<'_, Result<State, DynError>>;
+
+    fn dyn_set_state(&mut self, state: State) -> BoxFuture<'_, Result<(), DynError>>;
+
+    fn dyn_capabilities(&self) -> BoxFuture<'_, Result<LightCapabilities, DynError>>;
+
+    fn dyn_toggle(&mut self) -> BoxFuture<'_, Result<(), DynError>> {
+        unsupported("toggling")
+    }
+
+    fn dyn_turn_to_temperature(
+        &mut self,
+        _temperature: Kelvin,
+    ) -> BoxFuture<'_, Result<(), DynError>> {
+        unsupported("color temperature")
+    }
+
+    fn dyn_turn_to_color(&mut self, _color: Oklch) -> BoxFuture<'_, Result<(), DynError>> {
+        unsupported("color")
+    }
+
+    fn dyn_turn_to_brightness(
+        &mut self,
+        _brightness: Brightness,
+    ) -> BoxFuture<'_, Result<(), DynError>> {
+        unsupported("brightness")
+    }
+
+    fn dyn_apply_setting(&mut self, _setting: LightSetting) -> BoxFuture<'_, Result<(), DynError>> {
+        unsupported("applying settings")
+    }
+
+    fn dyn_get_setting(&self) -> BoxFuture<'_, Result<LightSetting, DynError>> {
+        unsupported("getting settings")
+    }
+
+    fn boxed(self) -> Box<dyn DynLight>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+impl<Light> DynLight for Light
+where
+    Light: GetState + SetState + Capabilities + Send + Sync,
+    <Light as GetState>::Error: Send + Sync + 'static,
+    <Light as SetState>::Error: Send + Sync + 'static,
+    <Light as Capabilities>::Error: Send + Sync + 'static,
+{
+    fn dyn_get_state(&self) -> BoxFuture<'_, Result<State, DynError>> {
+        erase(self.get_state())
+    }
+
+    fn dyn_set_state(&mut self, state: State) -> BoxFuture<'_, Result<(), DynError>> {
+        erase(self.set_state(state))
+    }
+
+    fn dyn_capabilities(&self) -> BoxFuture<'_, Result<LightCapabilities, DynError>> {
+        erase(self.capabilities())
+    }
+}
+
+/// Sends a command given as `Arg` to the light, with the light's own error erased
+type ForwardCommand<Light, Arg> = fn(&mut Light, Arg) -> BoxFuture<'_, Result<(), DynError>>;
+
+/// Reads something from the light, with the light's own error erased
+type ForwardRead<Light, T> = fn(&Light) -> BoxFuture<'_, Result<T, DynError>>;
+
+/// A light that forwards the optional [`DynLight`] methods it was built with, like
+///
+/// ```ignore
+/// let light = ErasedLight::new(light).with_color().with_brightness().boxed();
+/// ```
+///
+/// It deliberately doesn't implement the light traits itself, since it's only meant to be boxed.
+#[derive(Debug)]
+pub struct ErasedLight<Light> {
+    light: Light,
+    toggle: Option<ForwardCommand<Light, ()>>,
+    turn_to_temperature: Option<ForwardCommand<Light, Kelvin>>,
+    turn_to_color: Option<ForwardCommand<Light, Oklch>>,
+    turn_to_brightness: Option<ForwardCommand<Light, Brightness>>,
+    apply_setting: Option<ForwardCommand<Light, LightSetting>>,
+    get_setting: Option<ForwardRead<Light, LightSetting>>,
+}
+
+impl<Light> ErasedLight<Light> {
+    /// Forwards nothing but the required methods, until told otherwise
+    pub fn new(light: Light) -> Self {
+        Self {
+            light,
+            toggle: None,
+            turn_to_temperature: None,
+            turn_to_color: None,
+            turn_to_brightness: None,
+            apply_setting: None,
+            get_setting: None,
+        }
+    }
+
+    pub fn into_inner(self) -> Light {
+        self.light
+    }
+
+    pub fn with_toggle(mut self) -> Self
+    where
+        Light: Toggle,
+        Light::Error: Send + Sync + 'static,
+    {
+        self.toggle = Some(|light, ()| erase(light.toggle()));
+        self
+    }
+
+    pub fn with_temperature(mut self) -> Self
+    where
+        Light: TurnToTemperature,
+        Light::Error: Send + Sync + 'static,
+    {
+        self.turn_to_temperature =
+            Some(|light, temperature| erase(light.turn_to_temperature(temperature)));
+        self
+    }
+
+    pub fn with_color(mut self) -> Self
+    where
+        Light: TurnToColor,
+        Light::Error: Send + Sync + 'static,
+    {
+        self.turn_to_color = Some(|light, color| erase(light.turn_to_color(color)));
+        self
+    }
+
+    pub fn with_brightness(mut self) -> Self
+    where
+        Light: TurnToBrightness,
+        Light::Error: Send + Sync + 'static,
+    {
+        self.turn_to_brightness =
+            Some(|light, brightness| erase(light.turn_to_brightness(brightness)));
+        self
+    }
+
+    pub fn with_settings(mut self) -> Self
+    where
+        Light: ApplySetting + GetSetting,
+        <Light as ApplySetting>::Error: Send + Sync + 'static,
+        <Light as GetSetting>::Error: Send + Sync + 'static,
+    {
+        self.apply_setting = Some(|light, setting| erase(light.apply_setting(setting)));
+        self.get_setting = Some(|light| erase(light.get_setting()));
+        self
+    }
+
+    /// Forwards every light trait, for lights that implement them all
+    pub fn with_everything(self) -> Self
+    where
+        Light:
+            Toggle + TurnToTemperature + TurnToColor + TurnToBrightness + ApplySetting + GetSetting,
+        <Light as Toggle>::Error: Send + Sync + 'static,
+        <Light as TurnToTemperature>::Error: Send + Sync + 'static,
+        <Light as TurnToColor>::Error: Send + Sync + 'static,
+        <Light as TurnToBrightness>::Error: Send + Sync + 'static,
+        <Light as ApplySetting>::Error: Send + Sync + 'static,
+        <Light as GetSetting>::Error: Send + Sync + 'static,
+    {
+        self.with_toggle()
+            .with_temperature()
+            .with_color()
+            .with_brightness()
+            .with_settings()
+    }
+}
+
+impl<Light> DynLight for ErasedLight<Light>
+where
+    Light: DynLight,
+{
+    fn dyn_get_state(&self) -> BoxFuture<'_, Result<State, DynError>> {
+        self.light.dyn_get_state()
+    }
+
+    fn dyn_set_state(&mut self, state: State) -> BoxFuture<'_, Result<(), DynError>> {
+        self.light.dyn_set_state(state)
+    }
+
+    fn dyn_capabilities(&self) -> BoxFuture<'_, Result<LightCapabilities, DynError>> {
+        self.light.dyn_capabilities()
+    }
+
+    fn dyn_toggle(&mut self) -> BoxFuture<'_, Result<(), DynError>> {
+        match self.toggle {
+            Some(toggle) => toggle(&mut self.light, ()),
+            None => self.light.dyn_toggle(),
+        }
+    }
+
+    fn dyn_turn_to_temperature(
+        &mut self,
+        temperature: Kelvin,
+    ) -> BoxFuture<'_, Result<(), DynError>> {
+        match self.turn_to_temperature {
+            Some(turn_to_temperature) => turn_to_temperature(&mut self.light, temperature),
+            None => self.light.dyn_turn_to_temperature(temperature),
+        }
+    }
+
+    fn dyn_turn_to_color(&mut self, color: Oklch) -> BoxFuture<'_, Result<(), DynError>> {
+        match self.turn_to_color {
+            Some(turn_to_color) => turn_to_color(&mut self.light, color),
+            None => self.light.dyn_turn_to_color(color),
+        }
+    }
+
+    fn dyn_turn_to_brightness(
+        &mut self,
+        brightness: Brightness,
+    ) -> BoxFuture<'_, Result<(), DynError>> {
+        match self.turn_to_brightness {
+            Some(turn_to_brightness) => turn_to_brightness(&mut self.light, brightness),
+            None => self.light.dyn_turn_to_brightness(brightness),
+        }
+    }
+
+    fn dyn_apply_setting(&mut self, setting: LightSetting) -> BoxFuture<'_, Result<(), DynError>> {
+        match self.apply_setting {
+            Some(apply_setting) => apply_setting(&mut self.light, setting),
+            None => self.light.dyn_apply_setting(setting),
+        }
+    }
+
+    fn dyn_get_setting(&self) -> BoxFuture<'_, Result<LightSetting, DynError>> {
+        match self.get_setting {
+            Some(get_setting) => get_setting(&self.light),
+            None => self.light.dyn_get_setting(),
+        }
+    }
+}
+
+impl GetState for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn get_state(&self) -> impl Future<Output = Result<State, Self::Error>> + Send {
+        (**self).dyn_get_state()
+    }
+}
+
+impl SetState for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn set_state(&mut self, state: State) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        (**self).dyn_set_state(state)
+    }
+}
+
+impl Toggle for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn toggle(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        (**self).dyn_toggle()
+    }
+}
+
+impl TurnToTemperature for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn turn_to_temperature(
+        &mut self,
+        temperature: Kelvin,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        (**self).dyn_turn_to_temperature(temperature)
+    }
+}
+
+impl TurnToColor for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn turn_to_color(
+        &mut self,
+        color: Oklch,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        (**self).dyn_turn_to_color(color)
+    }
+}
+
+impl TurnToBrightness for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn turn_to_brightness(
+        &mut self,
+        brightness: Brightness,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        (**self).dyn_turn_to_brightness(brightness)
+    }
+}
+
+impl ApplySetting for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn apply_setting(
+        &mut self,
+        setting: LightSetting,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        (**self).dyn_apply_setting(setting)
+    }
+}
+
+impl GetSetting for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn get_setting(&self) -> impl Future<Output = Result<LightSetting, Self::Error>> + Send {
+        (**self).dyn_get_setting()
+    }
+}
+
+impl Capabilities for Box<dyn DynLight> {
+    type Error = DynError;
+
+    fn capabilities(&self) -> impl Future<Output = Result<LightCapabilities, Self::Error>> + Send {
+        (**self).dyn_capabilities()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DynError, DynLight, ErasedLight};
+    use crate::{
+        light::{
+            layer::{TimeoutLayer, WithLayer},
+            Brightness, Cached, Oklch, SetState, State, TurnToBrightness, TurnToColor,
+        },
+        testing::{Command, FakeLight},
+    };
+
+    #[tokio::test(start_paused = true)]
+    async fn wrapped_lights_of_different_types_go_together() {
+        let cached = FakeLight::default();
+        let layered = FakeLight::default();
+        let mut lights = vec![
+            Cached::new(cached.clone(), Duration::from_secs(60)).boxed(),
+            ErasedLight::new(
+                layered
+                    .clone()
+                    .with_layer(TimeoutLayer::new(Duration::from_secs(1))),
+            )
+            .with_color()
+            .boxed(),
+        ];
+
+        for light in &mut lights {
+            light.set_state(State::On).await.unwrap();
+        }
+        assert_eq!(cached.commands(), [Command::SetState(State::On)]);
+        assert_eq!(layered.commands(), [Command::SetState(State::On)]);
+
+        let color = Oklch::new(0.6, 0.15, 30.0);
+        assert!(matches!(
+            lights[0].turn_to_color(color).await,
+            Err(DynError::Unsupported { .. })
+        ));
+        lights[1].turn_to_color(color).await.unwrap();
+        assert_eq!(
+            layered.commands(),
+            [Command::SetState(State::On), Command::TurnToColor(color)]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn only_what_was_forwarded_is_supported() {
+        let fake = FakeLight::default();
+        let mut light = ErasedLight::new(fake.clone()).with_brightness().boxed();
+
+        let brightness = Brightness::new(50).unwrap();
+        light.turn_to_brightness(brightness).await.unwrap();
+        assert!(fake.commands()[0].turned_to_brightness(brightness));
+
+        assert!(matches!(
+            light.turn_to_color(Oklch::new(0.6, 0.15, 30.0)).await,
+            Err(DynError::Unsupported { operation: "color" })
+        ));
+    }
+}
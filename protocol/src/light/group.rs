@@ -0,0 +1,460 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+use futures_util::future::join_all;
+
+use super::{
+    Brightness, Capabilities, GetState, InvertToToggleError, Kelvin, LightCapabilities, Oklch,
+    SetState, State, TemperatureRange, Toggle, ToggleByInverting, TurnToBrightness, TurnToColor,
+    TurnToTemperature,
+};
+
+/// How a [`Group`] decides whether it's on or off from the states of its members
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, strum::Display, strum::EnumIs)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Aggregation {
+    /// On if at least one member is on
+    #[default]
+    AnyOn,
+    /// On only if every member is on
+    AllOn,
+    /// On if more than half of the members are on
+    Majority,
+}
+
+impl Aggregation {
+    /// An empty group is always considered off
+    pub fn aggregate(self, states: impl IntoIterator<Item = State>) -> State {
+        let (on, total) = states
+            .into_iter()
+            .fold((0usize, 0usize), |(on, total), state| {
+                (on + usize::from(state.is_on()), total + 1)
+            });
+
+        if total == 0 {
+            return State::Off;
+        }
+
+        let is_on = match self {
+            Aggregation::AnyOn => on > 0,
+            Aggregation::AllOn => on == total,
+            Aggregation::Majority => on * 2 > total,
+        };
+
+        is_on.into()
+    }
+}
+
+/// Many lights that can be controlled as one, with every command sent to every member concurrently.
+///
+/// The members can be of different types by making `Light` a `Box<dyn DynLight>`.
+#[derive(Debug, Clone)]
+pub struct Group<Id, Light> {
+    pub members: BTreeMap<Id, Light>,
+    pub aggregation: Aggregation,
+}
+
+impl<Id: Ord, Light> Group<Id, Light> {
+    pub fn new(aggregation: Aggregation) -> Self {
+        Self {
+            members: BTreeMap::new(),
+            aggregation,
+        }
+    }
+
+    pub fn with_member(mut self, id: Id, light: Light) -> Self {
+        self.members.insert(id, light);
+        self
+    }
+}
+
+impl<Id: Ord, Light> FromIterator<(Id, Light)> for Group<Id, Light> {
+    fn from_iter<T: IntoIterator<Item = (Id, Light)>>(iter: T) -> Self {
+        Self {
+            members: BTreeMap::from_iter(iter),
+            aggregation: Aggregation::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberFailure<Id, E> {
+    pub id: Id,
+    pub error: E,
+}
+
+/// Which members of a [`Group`] failed and why. Members not listed here succeeded
+#[derive(Debug, Clone)]
+pub struct GroupError<Id, E> {
+    pub failures: Vec<MemberFailure<Id, E>>,
+    pub total: usize,
+}
+
+impl<Id: Debug, E: Display> Display for GroupError<Id, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} lights in the group failed",
+            self.failures.len(),
+            self.total
+        )?;
+
+        for MemberFailure { id, error } in &self.failures {
+            write!(f, "; {id:?}: {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Id: Debug, E: Error> Error for GroupError<Id, E> {}
+
+pub(super) fn into_group_result<Id: Clone, T, E>(
+    total: usize,
+    results: Vec<(&Id, Result<T, E>)>,
+) -> Result<Vec<T>, GroupError<Id, E>> {
+    let mut successes = Vec::with_capacity(results.len());
+    let mut failures = Vec::new();
+
+    for (id, result) in results {
+        match result {
+            Ok(ok) => successes.push(ok),
+            Err(error) => failures.push(MemberFailure {
+                id: id.clone(),
+                error,
+            }),
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(successes)
+    } else {
+        Err(GroupError { failures, total })
+    }
+}
+
+impl<Id, Light> GetState for Group<Id, Light>
+where
+    Id: Clone + Ord + Debug + Send + Sync,
+    Light: GetState + Sync,
+    Light::Error: Send,
+{
+    type Error = GroupError<Id, Light::Error>;
+
+    /// Fails if getting the state of any member fails, rather than guess from only some of them
+    async fn get_state(&self) -> Result<State, Self::Error> {
+        let results = join_all(
+            self.members
+                .iter()
+                .map(|(id, light)| async move { (id, light.get_state().await) }),
+        )
+        .await;
+
+        let states = into_group_result(self.members.len(), results)?;
+
+        Ok(self.aggregation.aggregate(states))
+    }
+}
+
+impl<Id, Light> SetState for Group<Id, Light>
+where
+    Id: Clone + Ord + Debug + Send + Sync,
+    Light: SetState + Send,
+    Light::Error: Send,
+{
+    type Error = GroupError<Id, Light::Error>;
+
+    async fn set_state(&mut self, state: State) -> Result<(), Self::Error> {
+        let total = self.members.len();
+        let results = join_all(
+            self.members
+                .iter_mut()
+                .map(|(id, light)| async move { (id, light.set_state(state).await) }),
+        )
+        .await;
+
+        into_group_result(total, results)?;
+
+        Ok(())
+    }
+}
+
+impl<Id, Light> Toggle for Group<Id, Light>
+where
+    Id: Clone + Ord + Debug + Send + Sync + 'static,
+    Light: GetState + SetState + Send + Sync,
+    <Light as GetState>::Error: Send + 'static,
+    <Light as SetState>::Error: Send + 'static,
+{
+    type Error = InvertToToggleError<
+        GroupError<Id, <Light as GetState>::Error>,
+        GroupError<Id, <Light as SetState>::Error>,
+    >;
+
+    /// Sets every member to the inverse of the group's state,
+    /// rather than toggling each member and leaving the group as mixed as it was
+    async fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.toggle_by_inverting().await
+    }
+}
+
+impl<Id, Light> TurnToTemperature for Group<Id, Light>
+where
+    Id: Clone + Ord + Debug + Send + Sync,
+    Light: TurnToTemperature + Send,
+    Light::Error: Send,
+{
+    type Error = GroupError<Id, Light::Error>;
+
+    async fn turn_to_temperature(&mut self, temperature: Kelvin) -> Result<(), Self::Error> {
+        let total = self.members.len();
+        let results =
+            join_all(self.members.iter_mut().map(|(id, light)| async move {
+                (id, light.turn_to_temperature(temperature).await)
+            }))
+            .await;
+
+        into_group_result(total, results)?;
+
+        Ok(())
+    }
+}
+
+impl<Id, Light> TurnToColor for Group<Id, Light>
+where
+    Id: Clone + Ord + Debug + Send + Sync,
+    Light: TurnToColor + Send,
+    Light::Error: Send,
+{
+    type Error = GroupError<Id, Light::Error>;
+
+    async fn turn_to_color(&mut self, color: Oklch) -> Result<(), Self::Error> {
+        let total = self.members.len();
+        let results = join_all(
+            self.members
+                .iter_mut()
+                .map(|(id, light)| async move { (id, light.turn_to_color(color).await) }),
+        )
+        .await;
+
+        into_group_result(total, results)?;
+
+        Ok(())
+    }
+}
+
+impl<Id, Light> TurnToBrightness for Group<Id, Light>
+where
+    Id: Clone + Ord + Debug + Send + Sync,
+    Light: TurnToBrightness + Send,
+    Light::Error: Send,
+{
+    type Error = GroupError<Id, Light::Error>;
+
+    async fn turn_to_brightness(&mut self, brightness: Brightness) -> Result<(), Self::Error> {
+        let total = self.members.len();
+        let results =
+            join_all(self.members.iter_mut().map(|(id, light)| async move {
+                (id, light.turn_to_brightness(brightness).await)
+            }))
+            .await;
+
+        into_group_result(total, results)?;
+
+        Ok(())
+    }
+}
+
+impl<Id, Light> Capabilities for Group<Id, Light>
+where
+    Id: Clone + Ord + Debug + Send + Sync,
+    Light: Capabilities + Sync,
+    Light::Error: Send,
+{
+    type Error = GroupError<Id, Light::Error>;
+
+    /// Only what every member can do, so that a command the group supports works on all of them.
+    /// An empty group can only be turned on and off
+    async fn capabilities(&self) -> Result<LightCapabilities, Self::Error> {
+        let results = join_all(
+            self.members
+                .iter()
+                .map(|(id, light)| async move { (id, light.capabilities().await) }),
+        )
+        .await;
+
+        let capabilities = into_group_result(self.members.len(), results)?;
+
+        Ok(capabilities
+            .into_iter()
+            .reduce(|common, capabilities| LightCapabilities {
+                dimmable: common.dimmable && capabilities.dimmable,
+                color: common.color && capabilities.color,
+                temperature: common
+                    .temperature
+                    .zip(capabilities.temperature)
+                    .map(|(common, range)| TemperatureRange {
+                        min: common.min.max(range.min),
+                        max: common.max.min(range.max),
+                    })
+                    .filter(|range| range.min <= range.max),
+            })
+            .unwrap_or(LightCapabilities::ON_OFF))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aggregation, Group};
+    use crate::{
+        light::{
+            Capabilities, GetState, Kelvin, LightCapabilities, LightSetting, SetState, State,
+            TemperatureRange,
+        },
+        testing::{Command, FakeLight},
+    };
+
+    fn group(aggregation: Aggregation, states: &[State]) -> Group<usize, FakeLight> {
+        states
+            .iter()
+            .enumerate()
+            .fold(Group::new(aggregation), |group, (id, &state)| {
+                let setting = match state {
+                    State::On => LightSetting::ON,
+                    State::Off => LightSetting::OFF,
+                };
+                group.with_member(id, FakeLight::new(setting))
+            })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn any_on_is_on_with_a_single_member_on() {
+        use State::{Off, On};
+
+        let aggregation = Aggregation::AnyOn;
+        assert_eq!(
+            group(aggregation, &[Off, On, Off])
+                .get_state()
+                .await
+                .unwrap(),
+            On
+        );
+        assert_eq!(
+            group(aggregation, &[Off, Off]).get_state().await.unwrap(),
+            Off
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn all_on_is_off_with_a_single_member_off() {
+        use State::{Off, On};
+
+        let aggregation = Aggregation::AllOn;
+        assert_eq!(group(aggregation, &[On, On]).get_state().await.unwrap(), On);
+        assert_eq!(
+            group(aggregation, &[On, Off, On])
+                .get_state()
+                .await
+                .unwrap(),
+            Off
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn majority_is_off_on_a_tie() {
+        use State::{Off, On};
+
+        let aggregation = Aggregation::Majority;
+        assert_eq!(
+            group(aggregation, &[On, On, Off])
+                .get_state()
+                .await
+                .unwrap(),
+            On
+        );
+        assert_eq!(
+            group(aggregation, &[On, Off, On, Off])
+                .get_state()
+                .await
+                .unwrap(),
+            Off
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn an_empty_group_is_off() {
+        for aggregation in [
+            Aggregation::AnyOn,
+            Aggregation::AllOn,
+            Aggregation::Majority,
+        ] {
+            assert_eq!(
+                group(aggregation, &[]).get_state().await.unwrap(),
+                State::Off
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reports_every_failed_member_and_still_commands_the_rest() {
+        let mut group = group(Aggregation::AnyOn, &[State::Off; 3]);
+        group.members[&0].fail_next(1);
+        group.members[&2].fail_next(1);
+
+        let error = group.set_state(State::On).await.unwrap_err();
+
+        assert_eq!(error.total, 3);
+        let failed: Vec<usize> = error.failures.iter().map(|failure| failure.id).collect();
+        assert_eq!(failed, [0, 2]);
+        assert_eq!(group.members[&1].commands(), [Command::SetState(State::On)]);
+        assert!(group.members[&0].commands().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_to_get_the_state_if_any_member_does() {
+        let group = group(Aggregation::AnyOn, &[State::On, State::Off]);
+        group.members[&1].fail_next(1);
+
+        let error = group.get_state().await.unwrap_err();
+
+        assert_eq!(error.failures.len(), 1);
+        assert_eq!(error.failures[0].id, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn can_only_do_what_every_member_can() {
+        let range = |min, max| TemperatureRange {
+            min: Kelvin::new(min).unwrap(),
+            max: Kelvin::new(max).unwrap(),
+        };
+        let group = Group::new(Aggregation::AnyOn)
+            .with_member(
+                0,
+                FakeLight::default().with_capabilities(LightCapabilities {
+                    dimmable: true,
+                    color: false,
+                    temperature: Some(range(2200, 6500)),
+                }),
+            )
+            .with_member(
+                1,
+                FakeLight::default().with_capabilities(LightCapabilities {
+                    dimmable: true,
+                    color: true,
+                    temperature: Some(range(2700, 9000)),
+                }),
+            );
+
+        assert_eq!(
+            group.capabilities().await.unwrap(),
+            LightCapabilities {
+                dimmable: true,
+                color: false,
+                temperature: Some(range(2700, 6500)),
+            }
+        );
+    }
+}
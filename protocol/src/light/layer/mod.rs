@@ -0,0 +1,200 @@
+//! Wrappers that add behavior around every call to a light, in the spirit of tower's layers.
+//!
+//! Each wrapper implements every light trait its inner light does, so they can be stacked:
+//!
+//! ```ignore
+//! let light = light
+//!     .with_layer(TimeoutLayer::new(Duration::from_secs(2)))
+//!     .with_layer(RetryLayer::new(FibonacciBuilder::default()))
+//!     .with_layer(TraceLayer::new("living room"));
+//! ```
+
+/// Implements every light trait for a wrapper with an `inner` light,
+/// by passing the name of each call and the call itself to `$wrap`
+macro_rules! impl_light_traits {
+    (
+        impl<Light $(, $generic:ident)*> for $wrapper:ident
+        where ($($where:tt)*)
+        type Error<E> = $error:ident;
+        wrap with $wrap:ident;
+    ) => {
+        impl<Light $(, $generic)*> $crate::light::GetState for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::GetState + Send + Sync,
+            <Light as $crate::light::GetState>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::GetState>::Error>;
+
+            async fn get_state(&self) -> Result<$crate::light::State, Self::Error> {
+                $wrap!(self, "get_state", self.inner.get_state())
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::SetState for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::SetState + Send + Sync,
+            <Light as $crate::light::SetState>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::SetState>::Error>;
+
+            async fn set_state(&mut self, state: $crate::light::State) -> Result<(), Self::Error> {
+                $wrap!(self, "set_state", self.inner.set_state(state))
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::Toggle for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::Toggle + Send + Sync,
+            <Light as $crate::light::Toggle>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::Toggle>::Error>;
+
+            async fn toggle(&mut self) -> Result<(), Self::Error> {
+                $wrap!(self, "toggle", self.inner.toggle())
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::TurnToTemperature for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::TurnToTemperature + Send + Sync,
+            <Light as $crate::light::TurnToTemperature>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::TurnToTemperature>::Error>;
+
+            async fn turn_to_temperature(
+                &mut self,
+                temperature: $crate::light::Kelvin,
+            ) -> Result<(), Self::Error> {
+                $wrap!(self, "turn_to_temperature", self.inner.turn_to_temperature(temperature))
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::TurnToColor for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::TurnToColor + Send + Sync,
+            <Light as $crate::light::TurnToColor>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::TurnToColor>::Error>;
+
+            async fn turn_to_color(&mut self, color: $crate::light::Oklch) -> Result<(), Self::Error> {
+                $wrap!(self, "turn_to_color", self.inner.turn_to_color(color))
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::TurnToBrightness for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::TurnToBrightness + Send + Sync,
+            <Light as $crate::light::TurnToBrightness>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::TurnToBrightness>::Error>;
+
+            async fn turn_to_brightness(
+                &mut self,
+                brightness: $crate::light::Brightness,
+            ) -> Result<(), Self::Error> {
+                $wrap!(self, "turn_to_brightness", self.inner.turn_to_brightness(brightness))
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::ApplySetting for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::ApplySetting + Send + Sync,
+            <Light as $crate::light::ApplySetting>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::ApplySetting>::Error>;
+
+            async fn apply_setting(
+                &mut self,
+                setting: $crate::light::LightSetting,
+            ) -> Result<(), Self::Error> {
+                $wrap!(self, "apply_setting", self.inner.apply_setting(setting))
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::GetSetting for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::GetSetting + Send + Sync,
+            <Light as $crate::light::GetSetting>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::GetSetting>::Error>;
+
+            async fn get_setting(&self) -> Result<$crate::light::LightSetting, Self::Error> {
+                $wrap!(self, "get_setting", self.inner.get_setting())
+            }
+        }
+
+        impl<Light $(, $generic)*> $crate::light::Capabilities for $wrapper<Light $(, $generic)*>
+        where
+            Light: $crate::light::Capabilities + Send + Sync,
+            <Light as $crate::light::Capabilities>::Error: Send + 'static,
+            $($where)*
+        {
+            type Error = $error<<Light as $crate::light::Capabilities>::Error>;
+
+            async fn capabilities(&self) -> Result<$crate::light::LightCapabilities, Self::Error> {
+                $wrap!(self, "capabilities", self.inner.capabilities())
+            }
+        }
+    };
+}
+
+mod rate_limit;
+mod retry;
+mod timeout;
+#[cfg(feature = "tracing")]
+mod trace;
+
+pub use rate_limit::{RateLimit, RateLimitLayer};
+pub use retry::{Retry, RetryLayer};
+pub use timeout::{Timeout, TimeoutError, TimeoutLayer};
+#[cfg(feature = "tracing")]
+pub use trace::{TraceLayer, Traced};
+
+/// For wrappers that fail with whatever error their inner light failed with
+type Same<E> = E;
+
+/// Something that wraps a light in another light
+pub trait Layer<Light> {
+    type Light;
+    fn layer(&self, light: Light) -> Self::Light;
+}
+
+/// Two layers as one, with `inner` wrapped closest to the light
+#[derive(Debug, Clone)]
+pub struct Stack<Inner, Outer> {
+    pub inner: Inner,
+    pub outer: Outer,
+}
+
+impl<Inner, Outer> Stack<Inner, Outer> {
+    pub fn new(inner: Inner, outer: Outer) -> Self {
+        Self { inner, outer }
+    }
+}
+
+impl<Light, Inner, Outer> Layer<Light> for Stack<Inner, Outer>
+where
+    Inner: Layer<Light>,
+    Outer: Layer<Inner::Light>,
+{
+    type Light = Outer::Light;
+
+    fn layer(&self, light: Light) -> Self::Light {
+        self.outer.layer(self.inner.layer(light))
+    }
+}
+
+#[ext_trait::extension(pub trait WithLayer)]
+impl<Light> Light {
+    fn with_layer<L: Layer<Light>>(self, layer: L) -> L::Light {
+        layer.layer(self)
+    }
+}
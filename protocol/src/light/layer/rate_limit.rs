@@ -0,0 +1,98 @@
+use std::{num::NonZero, sync::Arc, time::Duration};
+
+use tokio::{sync::Mutex, time::Instant};
+
+use super::{Layer, Same};
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    refill_every: Duration,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    /// How long to wait before trying again, if there was no token to take
+    fn try_take(&mut self) -> Option<Duration> {
+        let now = Instant::now();
+        let refilled = (now - self.refilled_at).as_secs_f64() / self.refill_every.as_secs_f64();
+        self.tokens = (self.tokens + refilled).min(self.capacity);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(self.refill_every.mul_f64(1.0 - self.tokens))
+        }
+    }
+}
+
+/// Holds calls to the light back so that, over time, there is at most one every `refill_every`,
+/// while still letting bursts of up to `capacity` calls through at once
+#[derive(Debug, Clone)]
+pub struct RateLimit<Light> {
+    pub inner: Light,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl<Light> RateLimit<Light> {
+    async fn acquire(&self) {
+        loop {
+            let wait = self.bucket.lock().await.try_take();
+
+            match wait {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => return,
+            }
+        }
+    }
+}
+
+/// Every light wrapped by the same layer shares one budget,
+/// which suits lights that are all behind the same hub or rate limited API
+#[derive(Debug, Clone)]
+pub struct RateLimitLayer {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl RateLimitLayer {
+    pub fn new(capacity: NonZero<u32>, refill_every: Duration) -> Self {
+        let capacity = f64::from(capacity.get());
+
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                capacity,
+                refill_every,
+                tokens: capacity,
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+}
+
+impl<Light> Layer<Light> for RateLimitLayer {
+    type Light = RateLimit<Light>;
+
+    fn layer(&self, light: Light) -> Self::Light {
+        RateLimit {
+            inner: light,
+            bucket: self.bucket.clone(),
+        }
+    }
+}
+
+macro_rules! rate_limit {
+    ($this:ident, $command:literal, $call:expr) => {{
+        $this.acquire().await;
+        $call.await
+    }};
+}
+
+impl_light_traits! {
+    impl<Light> for RateLimit
+    where ()
+    type Error<E> = Same;
+    wrap with rate_limit;
+}
@@ -0,0 +1,125 @@
+use backon::BackoffBuilder;
+
+use super::{Layer, Same};
+
+/// Tries failed calls to the light again after waiting however long `backoff` says,
+/// failing with the last error once it gives up.
+///
+/// Toggling is never tried again, since a toggle that failed may still have reached the light
+/// and a second one would undo it.
+#[derive(Debug, Clone)]
+pub struct Retry<Light, Backoff> {
+    pub inner: Light,
+    pub backoff: Backoff,
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryLayer<Backoff> {
+    pub backoff: Backoff,
+}
+
+impl<Backoff: BackoffBuilder + Clone> RetryLayer<Backoff> {
+    pub fn new(backoff: Backoff) -> Self {
+        Self { backoff }
+    }
+}
+
+impl<Light, Backoff: BackoffBuilder + Clone> Layer<Light> for RetryLayer<Backoff> {
+    type Light = Retry<Light, Backoff>;
+
+    fn layer(&self, light: Light) -> Self::Light {
+        Retry {
+            inner: light,
+            backoff: self.backoff.clone(),
+        }
+    }
+}
+
+macro_rules! retry {
+    ($this:ident, "toggle", $call:expr) => {
+        $call.await
+    };
+    ($this:ident, $command:literal, $call:expr) => {{
+        let mut delays = $this.backoff.clone().build();
+
+        loop {
+            match $call.await {
+                Ok(ok) => break Ok(ok),
+                Err(error) => match delays.next() {
+                    Some(delay) => tokio::time::sleep(delay).await,
+                    None => break Err(error),
+                },
+            }
+        }
+    }};
+}
+
+impl_light_traits! {
+    impl<Light, Backoff> for Retry
+    where (
+        Backoff: BackoffBuilder + Clone + Send + Sync,
+        Backoff::Backoff: Send,
+    )
+    type Error<E> = Same;
+    wrap with retry;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use backon::ConstantBuilder;
+
+    use super::RetryLayer;
+    use crate::{
+        light::{layer::WithLayer, SetState, State, Toggle},
+        testing::FakeLight,
+    };
+
+    const DELAY: Duration = Duration::from_millis(100);
+
+    fn layer() -> RetryLayer<ConstantBuilder> {
+        RetryLayer::new(ConstantBuilder::new().with_delay(DELAY).with_max_times(2))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tries_again_after_the_delay() {
+        let fake = FakeLight::default();
+        fake.fail_next(2);
+        let mut light = fake.clone().with_layer(layer());
+
+        light.set_state(State::On).await.unwrap();
+
+        let log = fake.log();
+        assert_eq!(
+            log.iter()
+                .map(|recorded| recorded.failed)
+                .collect::<Vec<_>>(),
+            [true, true, false]
+        );
+        assert_eq!(log[1].at - log[0].at, DELAY);
+        assert_eq!(log[2].at - log[1].at, DELAY);
+        assert!(fake.setting().state.is_on());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn gives_up_after_the_backoff_does() {
+        let fake = FakeLight::default();
+        fake.fail_next(usize::MAX);
+        let mut light = fake.clone().with_layer(layer());
+
+        assert!(light.set_state(State::On).await.is_err());
+        assert_eq!(fake.log().len(), 3);
+        assert!(fake.setting().state.is_off());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn never_toggles_again() {
+        let fake = FakeLight::default();
+        fake.fail_next(1);
+        let mut light = fake.clone().with_layer(layer());
+
+        assert!(light.toggle().await.is_err());
+        assert_eq!(fake.log().len(), 1);
+    }
+}
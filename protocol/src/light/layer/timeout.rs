@@ -0,0 +1,109 @@
+use std::{error::Error, time::Duration};
+
+use snafu::Snafu;
+
+use super::Layer;
+
+#[derive(Debug, Snafu)]
+pub enum TimeoutError<E: Error + 'static> {
+    #[snafu(display("the light didn't respond to {command} within {duration:?}"))]
+    Elapsed {
+        command: &'static str,
+        duration: Duration,
+    },
+    #[snafu(display("the light failed before the timeout"))]
+    Inner { source: E },
+}
+
+/// Fails any call to the light that takes longer than `duration`
+#[derive(Debug, Clone)]
+pub struct Timeout<Light> {
+    pub inner: Light,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TimeoutLayer {
+    pub duration: Duration,
+}
+
+impl TimeoutLayer {
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+
+impl<Light> Layer<Light> for TimeoutLayer {
+    type Light = Timeout<Light>;
+
+    fn layer(&self, light: Light) -> Self::Light {
+        Timeout {
+            inner: light,
+            duration: self.duration,
+        }
+    }
+}
+
+macro_rules! timeout {
+    ($this:ident, $command:literal, $call:expr) => {
+        match tokio::time::timeout($this.duration, $call).await {
+            Ok(result) => result.map_err(|source| TimeoutError::Inner { source }),
+            Err(_) => Err(TimeoutError::Elapsed {
+                command: $command,
+                duration: $this.duration,
+            }),
+        }
+    };
+}
+
+impl_light_traits! {
+    impl<Light> for Timeout
+    where ()
+    type Error<E> = TimeoutError;
+    wrap with timeout;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{TimeoutError, TimeoutLayer};
+    use crate::{
+        light::{layer::WithLayer, SetState, State},
+        testing::FakeLight,
+    };
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_slow_calls() {
+        let fake = FakeLight::default().with_latency(Duration::from_secs(3));
+        let mut light = fake
+            .clone()
+            .with_layer(TimeoutLayer::new(Duration::from_secs(2)));
+
+        let result = light.set_state(State::On).await;
+
+        assert!(matches!(
+            result,
+            Err(TimeoutError::Elapsed {
+                command: "set_state",
+                ..
+            })
+        ));
+        assert!(fake.log().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn passes_along_fast_calls_and_their_errors() {
+        let fake = FakeLight::default().with_latency(Duration::from_secs(1));
+        let mut light = fake
+            .clone()
+            .with_layer(TimeoutLayer::new(Duration::from_secs(2)));
+
+        light.set_state(State::On).await.unwrap();
+        assert!(fake.setting().state.is_on());
+
+        fake.fail_next(1);
+        let result = light.set_state(State::Off).await;
+        assert!(matches!(result, Err(TimeoutError::Inner { .. })));
+    }
+}
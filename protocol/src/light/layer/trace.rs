@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use tracing::Instrument;
+
+use super::{Layer, Same};
+
+/// Runs every call to the light in its own span, and logs how it went
+#[derive(Debug, Clone)]
+pub struct Traced<Light> {
+    pub inner: Light,
+    pub name: Arc<str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TraceLayer {
+    pub name: Arc<str>,
+}
+
+impl TraceLayer {
+    /// The name is recorded in every span, to tell lights apart
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl<Light> Layer<Light> for TraceLayer {
+    type Light = Traced<Light>;
+
+    fn layer(&self, light: Light) -> Self::Light {
+        Traced {
+            inner: light,
+            name: self.name.clone(),
+        }
+    }
+}
+
+macro_rules! trace {
+    ($this:ident, $command:literal, $call:expr) => {{
+        let span = tracing::info_span!("light", name = %$this.name, command = $command);
+        let result = $call.instrument(span.clone()).await;
+
+        span.in_scope(|| match &result {
+            Ok(_) => tracing::debug!("succeeded"),
+            Err(error) => tracing::warn!(%error, "failed"),
+        });
+
+        result
+    }};
+}
+
+impl_light_traits! {
+    impl<Light> for Traced
+    where ()
+    type Error<E> = Same;
+    wrap with trace;
+}
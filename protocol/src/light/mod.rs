@@ -0,0 +1,136 @@
+use std::{error::Error, future::Future};
+
+use deranged::{RangedU16, RangedU8};
+
+mod cache;
+mod coalesce;
+mod dynamic;
+mod group;
+pub mod layer;
+mod scene;
+mod setting;
+
+pub use super::on_off::{
+    GetState, InvertToToggleError, IsOff, IsOn, SetState, State, Toggle, ToggleByInverting,
+    TurnOff, TurnOn,
+};
+pub use cache::{CacheLayer, Cached};
+pub use coalesce::{Coalesce, CoalesceError};
+pub use dynamic::{BoxFuture, DynError, DynLight, ErasedLight};
+pub use group::{Aggregation, Group, GroupError, MemberFailure};
+pub use scene::{ApplySceneError, Scene};
+pub use setting::{ApplySetting, ColorSetting, GetSetting, LightSetting};
+
+pub type Kelvin = RangedU16<2000, 10000>;
+
+pub trait TurnToTemperature {
+    type Error: Error;
+    fn turn_to_temperature(
+        &mut self,
+        temperature: Kelvin,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub type Oklch = palette::Oklch<f64>;
+
+pub trait TurnToColor {
+    type Error: Error;
+    fn turn_to_color(
+        &mut self,
+        color: Oklch,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Brightness as a percentage of the light's maximum
+pub type Brightness = RangedU8<0, 100>;
+
+pub trait TurnToBrightness {
+    type Error: Error;
+    fn turn_to_brightness(
+        &mut self,
+        brightness: Brightness,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// The warmest and coolest color temperatures a light can turn to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TemperatureRange {
+    pub min: Kelvin,
+    pub max: Kelvin,
+}
+
+impl TemperatureRange {
+    pub fn contains(&self, temperature: Kelvin) -> bool {
+        self.min <= temperature && temperature <= self.max
+    }
+
+    /// The closest temperature to the one given that the light can actually turn to.
+    ///
+    /// A range whose ends were reported the wrong way around is read as if they were swapped,
+    /// since it comes from drivers and Home Assistant rather than being checked.
+    pub fn clamp(&self, temperature: Kelvin) -> Kelvin {
+        temperature.clamp(self.min.min(self.max), self.min.max(self.max))
+    }
+}
+
+/// What a light can do, as discovered at runtime.
+/// Implementing a trait like [`TurnToColor`] only means a light can be asked to do it;
+/// these say whether the light itself can actually follow through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LightCapabilities {
+    pub dimmable: bool,
+    pub color: bool,
+    /// `None` if the light can't change its color temperature
+    pub temperature: Option<TemperatureRange>,
+}
+
+impl LightCapabilities {
+    /// A light that can only be turned on and off
+    pub const ON_OFF: Self = Self {
+        dimmable: false,
+        color: false,
+        temperature: None,
+    };
+
+    pub fn supports_temperature(&self) -> bool {
+        self.temperature.is_some()
+    }
+
+    /// Whether this is a light that can only make (shades of) white
+    pub fn is_white_only(&self) -> bool {
+        !self.color
+    }
+}
+
+pub trait Capabilities {
+    type Error: Error;
+    fn capabilities(&self) -> impl Future<Output = Result<LightCapabilities, Self::Error>> + Send;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Kelvin, TemperatureRange};
+
+    #[test]
+    fn clamps_to_a_range_reported_the_wrong_way_around() {
+        let range = TemperatureRange {
+            min: Kelvin::new(6500).unwrap(),
+            max: Kelvin::new(2700).unwrap(),
+        };
+
+        assert_eq!(
+            range.clamp(Kelvin::new(2000).unwrap()),
+            Kelvin::new(2700).unwrap()
+        );
+        assert_eq!(
+            range.clamp(Kelvin::new(4000).unwrap()),
+            Kelvin::new(4000).unwrap()
+        );
+        assert_eq!(
+            range.clamp(Kelvin::new(9000).unwrap()),
+            Kelvin::new(6500).unwrap()
+        );
+    }
+}
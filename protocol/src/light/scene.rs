@@ -0,0 +1,154 @@
+use std::{collections::BTreeMap, fmt::Debug};
+
+use futures_util::future::join_all;
+use snafu::{ResultExt, Snafu};
+
+use super::{
+    group::{into_group_result, GroupError, MemberFailure},
+    ApplySetting, Capabilities, GetSetting, LightSetting,
+};
+
+/// What every light in a scene should look like, keyed by whatever identifies the lights
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "Id: Ord + serde::Serialize",
+        deserialize = "Id: Ord + serde::Deserialize<'de>"
+    ))
+)]
+pub struct Scene<Id> {
+    pub settings: BTreeMap<Id, LightSetting>,
+}
+
+impl<Id> Default for Scene<Id> {
+    fn default() -> Self {
+        Self {
+            settings: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Id: Ord> Scene<Id> {
+    pub fn with_setting(mut self, id: Id, setting: LightSetting) -> Self {
+        self.settings.insert(id, setting);
+        self
+    }
+}
+
+impl<Id: Ord> FromIterator<(Id, LightSetting)> for Scene<Id> {
+    fn from_iter<T: IntoIterator<Item = (Id, LightSetting)>>(iter: T) -> Self {
+        Self {
+            settings: BTreeMap::from_iter(iter),
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum ApplySceneError<CapabilitiesError, ApplySettingError>
+where
+    CapabilitiesError: std::error::Error + 'static,
+    ApplySettingError: std::error::Error + 'static,
+{
+    #[snafu(display("the scene has a setting for a light that wasn't given"))]
+    Missing,
+    Capabilities {
+        source: CapabilitiesError,
+    },
+    ApplySetting {
+        source: ApplySettingError,
+    },
+}
+
+impl<Id> Scene<Id>
+where
+    Id: Clone + Ord + Debug + Send + Sync,
+{
+    /// Apply every setting to its light concurrently,
+    /// each first fit to what that light can actually do (see [`LightSetting::fit_to`]).
+    ///
+    /// Lights without a setting in the scene are left alone.
+    pub async fn apply<Light>(
+        &self,
+        lights: &mut BTreeMap<Id, Light>,
+    ) -> Result<
+        (),
+        GroupError<
+            Id,
+            ApplySceneError<<Light as Capabilities>::Error, <Light as ApplySetting>::Error>,
+        >,
+    >
+    where
+        Light: ApplySetting + Capabilities + Send + Sync,
+        <Light as Capabilities>::Error: Send + 'static,
+        <Light as ApplySetting>::Error: Send + 'static,
+    {
+        let missing: Vec<_> = self
+            .settings
+            .keys()
+            .filter(|id| !lights.contains_key(id))
+            .map(|id| (id, Err(ApplySceneError::Missing)))
+            .collect();
+
+        let present = lights.iter_mut().filter_map(|(id, light)| {
+            let setting = *self.settings.get(id)?;
+
+            Some(async move {
+                let result = async {
+                    let capabilities = light.capabilities().await.context(CapabilitiesSnafu)?;
+
+                    light
+                        .apply_setting(setting.fit_to(&capabilities))
+                        .await
+                        .context(ApplySettingSnafu)
+                }
+                .await;
+
+                (id, result)
+            })
+        });
+
+        let mut results = join_all(present).await;
+        results.extend(missing);
+
+        into_group_result(self.settings.len(), results)?;
+
+        Ok(())
+    }
+
+    /// Snapshot what the lights currently look like, to [`apply`](Self::apply) again later.
+    ///
+    /// Lights that fail to report their setting are left out of the scene and returned alongside it.
+    pub async fn capture<Light>(
+        lights: &BTreeMap<Id, Light>,
+    ) -> (Self, Vec<MemberFailure<Id, Light::Error>>)
+    where
+        Light: GetSetting + Sync,
+        Light::Error: Send,
+    {
+        let results = join_all(
+            lights
+                .iter()
+                .map(|(id, light)| async move { (id, light.get_setting().await) }),
+        )
+        .await;
+
+        let mut settings = BTreeMap::new();
+        let mut failures = Vec::new();
+
+        for (id, result) in results {
+            match result {
+                Ok(setting) => {
+                    settings.insert(id.clone(), setting);
+                }
+                Err(error) => failures.push(MemberFailure {
+                    id: id.clone(),
+                    error,
+                }),
+            }
+        }
+
+        (Self { settings }, failures)
+    }
+}
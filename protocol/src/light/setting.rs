@@ -0,0 +1,85 @@
+use std::{error::Error, future::Future, time::Duration};
+
+use super::{Brightness, Kelvin, LightCapabilities, Oklch, State};
+use crate::color::{kelvin_to_oklch, oklch_to_kelvin};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorSetting {
+    Color(Oklch),
+    Temperature(Kelvin),
+}
+
+/// Everything about what a light should look like, to be applied all at once
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LightSetting {
+    /// When this is [`State::Off`], the rest of the setting is only kept for reference
+    pub state: State,
+    /// `None` to keep whatever color or temperature the light already has
+    pub color: Option<ColorSetting>,
+    /// `None` to keep whatever brightness the light already has
+    pub brightness: Option<Brightness>,
+    /// How long the light should take to get there, or `None` for the light's default
+    pub transition: Option<Duration>,
+}
+
+impl LightSetting {
+    pub const OFF: Self = Self {
+        state: State::Off,
+        color: None,
+        brightness: None,
+        transition: None,
+    };
+
+    pub const ON: Self = Self {
+        state: State::On,
+        color: None,
+        brightness: None,
+        transition: None,
+    };
+
+    /// The closest setting to this one that a light with these capabilities can actually show.
+    ///
+    /// Colors become the nearest color temperature on lights that can only make white,
+    /// temperatures become the color of a black body on lights that can only make colors
+    /// or are clamped to the light's range,
+    /// and whatever the light can't do at all is dropped.
+    pub fn fit_to(self, capabilities: &LightCapabilities) -> Self {
+        let color = self.color.and_then(|color| match color {
+            ColorSetting::Color(_) if capabilities.color => Some(color),
+            ColorSetting::Color(color) => capabilities
+                .temperature
+                .map(|range| ColorSetting::Temperature(range.clamp(oklch_to_kelvin(color)))),
+            ColorSetting::Temperature(temperature) => match capabilities.temperature {
+                Some(range) => Some(ColorSetting::Temperature(range.clamp(temperature))),
+                None if capabilities.color => {
+                    Some(ColorSetting::Color(kelvin_to_oklch(temperature)))
+                }
+                None => None,
+            },
+        });
+
+        let brightness = self.brightness.filter(|_| capabilities.dimmable);
+
+        Self {
+            color,
+            brightness,
+            ..self
+        }
+    }
+}
+
+pub trait ApplySetting {
+    type Error: Error;
+    fn apply_setting(
+        &mut self,
+        setting: LightSetting,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Read back what a light currently looks like
+pub trait GetSetting {
+    type Error: Error;
+    fn get_setting(&self) -> impl Future<Output = Result<LightSetting, Self::Error>> + Send;
+}
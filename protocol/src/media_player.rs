@@ -0,0 +1,188 @@
+use std::{error::Error, future::Future, time::Duration};
+
+use deranged::RangedU8;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display, strum::EnumIs,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PlaybackState {
+    Off,
+    On,
+    Idle,
+    Playing,
+    Paused,
+    Standby,
+    Buffering,
+}
+
+pub trait GetPlaybackState {
+    type Error: Error;
+    fn get_playback_state(&self)
+        -> impl Future<Output = Result<PlaybackState, Self::Error>> + Send;
+}
+
+#[ext_trait::extension(pub trait IsPlaying)]
+impl<T: GetPlaybackState + Sync> T {
+    // spelled out rather than `async fn` so that the future is `Send`
+    #[allow(clippy::manual_async_fn)]
+    fn is_playing(&self) -> impl Future<Output = Result<bool, T::Error>> + Send {
+        async move { Ok(self.get_playback_state().await?.is_playing()) }
+    }
+}
+
+pub trait Play {
+    type Error: Error;
+    fn play(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait Pause {
+    type Error: Error;
+    fn pause(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait Stop {
+    type Error: Error;
+    fn stop(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Volume as a percentage of the media player's maximum
+pub type Volume = RangedU8<0, 100>;
+
+pub trait SetVolume {
+    type Error: Error;
+    fn set_volume(
+        &mut self,
+        volume: Volume,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display, strum::EnumIs,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum VolumeStep {
+    Down,
+    Up,
+}
+
+/// Step the volume by however much the media player considers to be one step
+pub trait StepVolume {
+    type Error: Error;
+    fn step_volume(
+        &mut self,
+        step: VolumeStep,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+#[ext_trait::extension(pub trait VolumeDown)]
+impl<T: StepVolume> T {
+    fn volume_down(&mut self) -> impl Future<Output = Result<(), T::Error>> + Send {
+        self.step_volume(VolumeStep::Down)
+    }
+}
+
+#[ext_trait::extension(pub trait VolumeUp)]
+impl<T: StepVolume> T {
+    fn volume_up(&mut self) -> impl Future<Output = Result<(), T::Error>> + Send {
+        self.step_volume(VolumeStep::Up)
+    }
+}
+
+pub trait SetMuted {
+    type Error: Error;
+    fn set_muted(&mut self, muted: bool) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+#[ext_trait::extension(pub trait Mute)]
+impl<T: SetMuted> T {
+    fn mute(&mut self) -> impl Future<Output = Result<(), T::Error>> + Send {
+        self.set_muted(true)
+    }
+}
+
+#[ext_trait::extension(pub trait Unmute)]
+impl<T: SetMuted> T {
+    fn unmute(&mut self) -> impl Future<Output = Result<(), T::Error>> + Send {
+        self.set_muted(false)
+    }
+}
+
+pub trait SelectSource {
+    type Error: Error;
+    fn select_source(
+        &mut self,
+        source: String,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MediaContentType {
+    Album,
+    App,
+    Apps,
+    Artist,
+    Channel,
+    Channels,
+    Composer,
+    Contributing,
+    Episode,
+    Game,
+    Genre,
+    Image,
+    Movie,
+    Music,
+    Playlist,
+    Podcast,
+    Season,
+    Track,
+    #[strum(serialize = "tvshow")]
+    TvShow,
+    Url,
+    Video,
+    #[strum(default)]
+    Other(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Media {
+    pub content_id: String,
+    pub content_type: MediaContentType,
+}
+
+pub trait PlayMedia {
+    type Error: Error;
+    fn play_media(&mut self, media: Media) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Information about whatever the media player is currently playing,
+/// with every piece being optional because it depends on both the kind of media and the media player
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MediaMetadata {
+    pub content_id: Option<String>,
+    pub content_type: Option<MediaContentType>,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album_name: Option<String>,
+    pub album_artist: Option<String>,
+    pub track: Option<u32>,
+    pub series_title: Option<String>,
+    pub season: Option<String>,
+    pub episode: Option<String>,
+    pub channel: Option<String>,
+    pub playlist: Option<String>,
+    pub app_name: Option<String>,
+    pub duration: Option<Duration>,
+    pub position: Option<Duration>,
+    pub image_url: Option<String>,
+}
+
+pub trait GetMediaMetadata {
+    type Error: Error;
+    fn get_media_metadata(&self)
+        -> impl Future<Output = Result<MediaMetadata, Self::Error>> + Send;
+}
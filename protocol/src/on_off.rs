@@ -1,6 +1,5 @@
 use std::{error::Error, future::Future};
 
-use deranged::RangedU16;
 use snafu::{ResultExt, Snafu};
 
 #[derive(
@@ -75,6 +74,11 @@ impl<T: SetState> T {
     }
 }
 
+/// Flip between on and off.
+///
+/// Implement this with whatever the backend offers to do it in one step,
+/// so that two toggles sent at once can't both read the same state and cancel out.
+/// Backends without one can use [`ToggleByInverting::toggle_by_inverting`].
 pub trait Toggle {
     type Error: Error;
     fn toggle(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
@@ -86,39 +90,30 @@ pub enum InvertToToggleError<GetStateError: Error + 'static, SetStateError: Erro
     SetStateError { source: SetStateError },
 }
 
-impl<T: GetState + SetState + Send> Toggle for T
+#[ext_trait::extension(pub trait ToggleByInverting)]
+impl<T: GetState + SetState + Send> T
 where
     <T as GetState>::Error: 'static,
     <T as SetState>::Error: 'static,
 {
-    type Error = InvertToToggleError<<T as GetState>::Error, <T as SetState>::Error>;
-    /// Toggle the light by setting it to the inverse of its current state
-    async fn toggle(&mut self) -> Result<(), Self::Error> {
-        let state = self.get_state().await.context(GetStateSnafu)?;
-        self.set_state(state.invert())
-            .await
-            .context(SetStateSnafu)?;
-
-        Ok(())
-    }
-}
-
-pub type Kelvin = RangedU16<2000, 10000>;
-
-pub trait TurnToTemperature {
-    type Error: Error;
-    fn turn_to_temperature(
+    /// Toggle by setting to the inverse of the current state.
+    ///
+    /// Something else can change the state between reading and setting it,
+    /// so prefer [`Toggle`] where the backend can do it in one step.
+    // spelled out rather than `async fn` so that the future is `Send`
+    #[allow(clippy::manual_async_fn)]
+    fn toggle_by_inverting(
         &mut self,
-        temperature: Kelvin,
-    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
-}
-
-pub type Oklch = palette::Oklch<f64>;
-
-pub trait TurnToColor {
-    type Error: Error;
-    fn turn_to_color(
-        &mut self,
-        color: Oklch,
-    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+    ) -> impl Future<
+        Output = Result<(), InvertToToggleError<<T as GetState>::Error, <T as SetState>::Error>>,
+    > + Send {
+        async move {
+            let state = self.get_state().await.context(GetStateSnafu)?;
+            self.set_state(state.invert())
+                .await
+                .context(SetStateSnafu)?;
+
+            Ok(())
+        }
+    }
 }
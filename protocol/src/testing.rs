@@ -0,0 +1,394 @@
+//! Lights that only exist in memory, for testing automations without any real devices
+
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use snafu::Snafu;
+use tokio::{sync::Notify, time::Instant};
+
+use crate::light::{
+    ApplySetting, Brightness, Capabilities, ColorSetting, GetSetting, GetState, Kelvin,
+    LightCapabilities, LightSetting, Oklch, SetState, State, TemperatureRange, Toggle,
+    TurnToBrightness, TurnToColor, TurnToTemperature,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, strum::EnumIs)]
+pub enum Command {
+    SetState(State),
+    /// With the state the toggle left the light in
+    Toggle(State),
+    TurnToTemperature(Kelvin),
+    TurnToColor(Oklch),
+    TurnToBrightness(Brightness),
+    ApplySetting(LightSetting),
+}
+
+impl Command {
+    /// What the light is left looking like after this command
+    fn apply_to(self, setting: &mut LightSetting) {
+        match self {
+            Command::SetState(state) | Command::Toggle(state) => setting.state = state,
+            Command::TurnToTemperature(temperature) => {
+                setting.state = State::On;
+                setting.color = Some(ColorSetting::Temperature(temperature));
+            }
+            Command::TurnToColor(color) => {
+                setting.state = State::On;
+                setting.color = Some(ColorSetting::Color(color));
+            }
+            Command::TurnToBrightness(brightness) => {
+                setting.state = State::On;
+                setting.brightness = Some(brightness);
+            }
+            Command::ApplySetting(new) => {
+                setting.state = new.state;
+                setting.color = new.color.or(setting.color);
+                setting.brightness = new.brightness.or(setting.brightness);
+            }
+        }
+    }
+
+    pub fn turned_to_temperature(&self, temperature: Kelvin) -> bool {
+        match self {
+            Command::TurnToTemperature(turned_to) => *turned_to == temperature,
+            Command::ApplySetting(setting) => {
+                setting.state.is_on()
+                    && setting.color == Some(ColorSetting::Temperature(temperature))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn turned_to_color(&self, color: Oklch) -> bool {
+        match self {
+            Command::TurnToColor(turned_to) => *turned_to == color,
+            Command::ApplySetting(setting) => {
+                setting.state.is_on() && setting.color == Some(ColorSetting::Color(color))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn turned_to_brightness(&self, brightness: Brightness) -> bool {
+        match self {
+            Command::TurnToBrightness(turned_to) => *turned_to == brightness,
+            Command::ApplySetting(setting) => {
+                setting.state.is_on() && setting.brightness == Some(brightness)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn turned_to_state(&self, state: State) -> bool {
+        match self {
+            Command::SetState(turned_to) | Command::Toggle(turned_to) => *turned_to == state,
+            Command::ApplySetting(setting) => setting.state == state,
+            Command::TurnToTemperature(_)
+            | Command::TurnToColor(_)
+            | Command::TurnToBrightness(_) => state.is_on(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Recorded {
+    pub at: Instant,
+    pub command: Command,
+    /// Whether the command was made to fail with [`FakeLight::fail_next`]
+    pub failed: bool,
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(display("the fake light was scripted to fail"))]
+pub struct ScriptedFailure;
+
+#[derive(Debug, Snafu)]
+#[snafu(display("no matching command was sent to the fake light within {within:?}"))]
+pub struct ExpectationTimeout {
+    pub within: Duration,
+}
+
+#[derive(Debug)]
+struct Inner {
+    setting: LightSetting,
+    capabilities: LightCapabilities,
+    latency: Option<Duration>,
+    failures_left: usize,
+    log: Vec<Recorded>,
+}
+
+/// A light that does whatever it's told and remembers being told.
+///
+/// Clones share the same light, so keep one to inspect after handing another to the code under test.
+/// Timestamps come from tokio's clock, so pausing time in tests works as expected.
+#[derive(Debug, Clone)]
+pub struct FakeLight {
+    inner: Arc<Mutex<Inner>>,
+    recorded: Arc<Notify>,
+}
+
+impl Default for FakeLight {
+    fn default() -> Self {
+        Self::new(LightSetting::OFF)
+    }
+}
+
+impl FakeLight {
+    /// Capable of everything by default
+    pub fn new(setting: LightSetting) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                setting,
+                capabilities: LightCapabilities {
+                    dimmable: true,
+                    color: true,
+                    temperature: Some(TemperatureRange {
+                        min: Kelvin::MIN,
+                        max: Kelvin::MAX,
+                    }),
+                },
+                latency: None,
+                failures_left: 0,
+                log: Vec::new(),
+            })),
+            recorded: Arc::new(Notify::new()),
+        }
+    }
+
+    pub fn with_capabilities(self, capabilities: LightCapabilities) -> Self {
+        self.lock().capabilities = capabilities;
+        self
+    }
+
+    /// How long every call takes before it does anything
+    pub fn with_latency(self, latency: Duration) -> Self {
+        self.lock().latency = Some(latency);
+        self
+    }
+
+    /// Make the next `count` calls fail, reads included
+    pub fn fail_next(&self, count: usize) {
+        self.lock().failures_left = count;
+    }
+
+    pub fn setting(&self) -> LightSetting {
+        self.lock().setting
+    }
+
+    pub fn log(&self) -> Vec<Recorded> {
+        self.lock().log.clone()
+    }
+
+    /// Only the commands that didn't fail, in the order they were sent
+    pub fn commands(&self) -> Vec<Command> {
+        self.lock()
+            .log
+            .iter()
+            .filter(|recorded| !recorded.failed)
+            .map(|recorded| recorded.command)
+            .collect()
+    }
+
+    pub fn clear_log(&self) {
+        self.lock().log.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .expect("nothing panics while holding the lock")
+    }
+
+    async fn delay(&self) -> Result<(), ScriptedFailure> {
+        let latency = self.lock().latency;
+        if let Some(latency) = latency {
+            tokio::time::sleep(latency).await;
+        }
+
+        let mut inner = self.lock();
+        if inner.failures_left > 0 {
+            inner.failures_left -= 1;
+            return Err(ScriptedFailure);
+        }
+
+        Ok(())
+    }
+
+    async fn read<T>(&self, read: impl FnOnce(&Inner) -> T) -> Result<T, ScriptedFailure> {
+        self.delay().await?;
+        Ok(read(&self.lock()))
+    }
+
+    async fn command(&self, command: Command) -> Result<(), ScriptedFailure> {
+        self.command_from(|_| command).await
+    }
+
+    /// For commands that depend on the setting the light is in when they arrive
+    async fn command_from(
+        &self,
+        command: impl FnOnce(&LightSetting) -> Command,
+    ) -> Result<(), ScriptedFailure> {
+        let result = self.delay().await;
+
+        {
+            let mut inner = self.lock();
+            let command = command(&inner.setting);
+            if result.is_ok() {
+                command.apply_to(&mut inner.setting);
+            }
+            inner.log.push(Recorded {
+                at: Instant::now(),
+                command,
+                failed: result.is_err(),
+            });
+        }
+        self.recorded.notify_waiters();
+
+        result
+    }
+
+    /// Wait until a command matching the predicate has been sent successfully,
+    /// counting commands that were already sent before this was called
+    pub async fn expect(
+        &self,
+        within: Duration,
+        predicate: impl Fn(&Command) -> bool,
+    ) -> Result<Recorded, ExpectationTimeout> {
+        let find = || {
+            self.lock()
+                .log
+                .iter()
+                .find(|recorded| !recorded.failed && predicate(&recorded.command))
+                .copied()
+        };
+
+        let wait = async {
+            loop {
+                let recorded = self.recorded.notified();
+                tokio::pin!(recorded);
+                recorded.as_mut().enable();
+
+                if let Some(found) = find() {
+                    return found;
+                }
+
+                recorded.await;
+            }
+        };
+
+        tokio::time::timeout(within, wait)
+            .await
+            .map_err(|_| ExpectationTimeout { within })
+    }
+
+    pub async fn expect_temperature(
+        &self,
+        temperature: Kelvin,
+        within: Duration,
+    ) -> Result<Recorded, ExpectationTimeout> {
+        self.expect(within, |command| command.turned_to_temperature(temperature))
+            .await
+    }
+
+    pub async fn expect_color(
+        &self,
+        color: Oklch,
+        within: Duration,
+    ) -> Result<Recorded, ExpectationTimeout> {
+        self.expect(within, |command| command.turned_to_color(color))
+            .await
+    }
+
+    pub async fn expect_brightness(
+        &self,
+        brightness: Brightness,
+        within: Duration,
+    ) -> Result<Recorded, ExpectationTimeout> {
+        self.expect(within, |command| command.turned_to_brightness(brightness))
+            .await
+    }
+
+    pub async fn expect_state(
+        &self,
+        state: State,
+        within: Duration,
+    ) -> Result<Recorded, ExpectationTimeout> {
+        self.expect(within, |command| command.turned_to_state(state))
+            .await
+    }
+}
+
+impl GetState for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn get_state(&self) -> Result<State, Self::Error> {
+        self.read(|inner| inner.setting.state).await
+    }
+}
+
+impl SetState for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn set_state(&mut self, state: State) -> Result<(), Self::Error> {
+        self.command(Command::SetState(state)).await
+    }
+}
+
+impl Toggle for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.command_from(|setting| Command::Toggle(setting.state.invert()))
+            .await
+    }
+}
+
+impl TurnToTemperature for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn turn_to_temperature(&mut self, temperature: Kelvin) -> Result<(), Self::Error> {
+        self.command(Command::TurnToTemperature(temperature)).await
+    }
+}
+
+impl TurnToColor for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn turn_to_color(&mut self, color: Oklch) -> Result<(), Self::Error> {
+        self.command(Command::TurnToColor(color)).await
+    }
+}
+
+impl TurnToBrightness for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn turn_to_brightness(&mut self, brightness: Brightness) -> Result<(), Self::Error> {
+        self.command(Command::TurnToBrightness(brightness)).await
+    }
+}
+
+impl ApplySetting for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn apply_setting(&mut self, setting: LightSetting) -> Result<(), Self::Error> {
+        self.command(Command::ApplySetting(setting)).await
+    }
+}
+
+impl GetSetting for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn get_setting(&self) -> Result<LightSetting, Self::Error> {
+        self.read(|inner| inner.setting).await
+    }
+}
+
+impl Capabilities for FakeLight {
+    type Error = ScriptedFailure;
+
+    async fn capabilities(&self) -> Result<LightCapabilities, Self::Error> {
+        self.read(|inner| inner.capabilities).await
+    }
+}
@@ -0,0 +1,48 @@
+use std::{error::Error, future::Future};
+
+pub use super::battery::{BatteryLevel, GetBatteryLevel};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display, strum::EnumIs,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Activity {
+    Cleaning,
+    Docked,
+    Idle,
+    Paused,
+    Returning,
+    Error,
+}
+
+pub trait GetActivity {
+    type Error: Error;
+    fn get_activity(&self) -> impl Future<Output = Result<Activity, Self::Error>> + Send;
+}
+
+pub trait Start {
+    type Error: Error;
+    fn start(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait Pause {
+    type Error: Error;
+    fn pause(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+pub trait ReturnToDock {
+    type Error: Error;
+    fn return_to_dock(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Make the vacuum announce where it is, usually by beeping or speaking
+pub trait Locate {
+    type Error: Error;
+    fn locate(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Clean only the area around wherever the vacuum currently is
+pub trait CleanSpot {
+    type Error: Error;
+    fn clean_spot(&mut self) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
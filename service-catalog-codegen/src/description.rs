@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+/// What `homeassistant.helpers.service.async_get_all_descriptions` returns, keyed by domain and then by service
+pub type Descriptions = BTreeMap<String, BTreeMap<String, ServiceDescription>>;
+
+#[derive(Debug, Deserialize)]
+pub struct ServiceDescription {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldOrSection>,
+    /// Only whether there is one matters, since `ServiceTarget` covers every kind of target
+    #[serde(default)]
+    pub target: Option<Value>,
+    #[serde(default)]
+    pub response: Option<Response>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Response {
+    /// When not optional, the service has to be asked for a response
+    #[serde(default)]
+    pub optional: bool,
+}
+
+/// Sections only group fields for the UI, and their fields are sent alongside every other field
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum FieldOrSection {
+    Section { fields: BTreeMap<String, Field> },
+    Field(Field),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Field {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub example: Option<Value>,
+    /// A single selector type like `number`, mapped to its configuration
+    #[serde(default)]
+    pub selector: Option<BTreeMap<String, Option<Value>>>,
+}
+
+impl ServiceDescription {
+    /// Every field, with the ones in sections pulled out of them
+    pub fn flat_fields(&self) -> BTreeMap<&str, &Field> {
+        let mut fields = BTreeMap::new();
+
+        for (key, field_or_section) in &self.fields {
+            match field_or_section {
+                FieldOrSection::Section {
+                    fields: section_fields,
+                } => {
+                    for (key, field) in section_fields {
+                        fields.insert(key.as_str(), field);
+                    }
+                }
+                FieldOrSection::Field(field) => {
+                    fields.insert(key.as_str(), field);
+                }
+            }
+        }
+
+        fields
+    }
+}
@@ -0,0 +1,515 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use serde_json::Value;
+
+use super::{
+    description::{Field, ServiceDescription},
+    selector::FieldType,
+};
+
+const HEADER: &str = "// Generated by service-catalog-codegen from Home Assistant's service descriptions, so regenerate it instead of editing it";
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The name of a module or field, which is also the name of its file.
+///
+/// Keywords get a `_` suffix rather than becoming raw identifiers,
+/// since `self`, `Self`, `super` and `crate` can't be raw identifiers.
+pub fn identifier(name: &str) -> String {
+    let mut identifier = name.to_snake_case();
+
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    if KEYWORDS.contains(&identifier.as_str()) {
+        identifier.push('_');
+    }
+
+    identifier
+}
+
+fn type_name(name: &str) -> String {
+    let mut type_name = name.to_upper_camel_case();
+
+    if !type_name.starts_with(|c: char| c.is_ascii_alphabetic()) || type_name == "Self" {
+        type_name.insert_str(0, "Service");
+    }
+
+    type_name
+}
+
+/// The `mod.rs` declaring one module per name
+pub fn parent_module(doc: &str, names: &[&str]) -> String {
+    let mut module = String::new();
+
+    writeln!(module, "{HEADER}").unwrap();
+    writeln!(module).unwrap();
+    writeln!(module, "//! {doc}").unwrap();
+    writeln!(module).unwrap();
+    for name in names {
+        writeln!(module, "pub mod {};", identifier(name)).unwrap();
+    }
+
+    module
+}
+
+struct GeneratedField<'a> {
+    key: &'a str,
+    identifier: String,
+    field: &'a Field,
+    field_type: FieldType,
+    type_name: String,
+}
+
+fn rust_type(field_type: &FieldType, enum_name: &str) -> String {
+    match field_type {
+        FieldType::Bool => "bool".to_owned(),
+        FieldType::Number { .. } => "f64".to_owned(),
+        FieldType::String => "String".to_owned(),
+        FieldType::Enum(_) => enum_name.to_owned(),
+        FieldType::EntityId => "EntityId".to_owned(),
+        FieldType::DeviceId => "DeviceId".to_owned(),
+        FieldType::AreaId => "AreaId".to_owned(),
+        FieldType::FloorId => "FloorId".to_owned(),
+        FieldType::LabelId => "LabelId".to_owned(),
+        FieldType::Rgb => "(u8, u8, u8)".to_owned(),
+        FieldType::Duration => "Duration".to_owned(),
+        FieldType::Arbitrary => "Arbitrary".to_owned(),
+        FieldType::List(field_type) => format!("Vec<{}>", rust_type(field_type, enum_name)),
+    }
+}
+
+fn doc_comment(out: &mut String, indent: &str, text: &str) {
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            writeln!(out, "{indent}///").unwrap();
+        } else {
+            writeln!(out, "{indent}/// {}", line.trim_end()).unwrap();
+        }
+    }
+}
+
+fn field_doc(field: &Field) -> String {
+    let mut doc = String::new();
+
+    match (&field.name, &field.description) {
+        (Some(name), Some(description)) => write!(doc, "{name}: {description}").unwrap(),
+        (Some(text), None) | (None, Some(text)) => doc.push_str(text),
+        (None, None) => {}
+    }
+
+    if let Some(example) = &field.example {
+        let example = match example {
+            Value::String(example) => example.clone(),
+            example => example.to_string(),
+        };
+        if !doc.is_empty() {
+            doc.push_str("\n\n");
+        }
+        write!(doc, "For example `{example}`").unwrap();
+    }
+
+    doc
+}
+
+/// A check that returns an error from `into_pyobject` when a number is out of the field's range
+fn range_check(out: &mut String, indent: &str, field: &GeneratedField) {
+    let FieldType::Number { min, max } = field.field_type else {
+        return;
+    };
+    let (key, identifier) = (field.key, &field.identifier);
+
+    let (condition, expected) = match (min, max) {
+        (Some(min), Some(max)) => (
+            format!("!({min:?}..={max:?}).contains(&{identifier})"),
+            format!("from {min} to {max}"),
+        ),
+        (Some(min), None) => (format!("{identifier} < {min:?}"), format!("at least {min}")),
+        (None, Some(max)) => (format!("{identifier} > {max:?}"), format!("at most {max}")),
+        (None, None) => return,
+    };
+
+    writeln!(out, "{indent}if {condition} {{").unwrap();
+    writeln!(
+        out,
+        "{indent}    return Err(PyValueError::new_err(format!(\"`{key}` has to be {expected}, but was {{}}\", {identifier})));"
+    )
+    .unwrap();
+    writeln!(out, "{indent}}}").unwrap();
+}
+
+fn set_item(out: &mut String, indent: &str, field: &GeneratedField) {
+    let (key, identifier) = (field.key, &field.identifier);
+
+    let value = match field.field_type {
+        FieldType::Duration => format!("time_period_dict(py, {identifier})?"),
+        _ => identifier.clone(),
+    };
+
+    writeln!(out, "{indent}dict.set_item(\"{key}\", {value})?;").unwrap();
+}
+
+/// One module per service, laid out like the hand-written ones such as `light::service::turn_on`
+pub fn service_module(
+    home_assistant: &str,
+    domain: &str,
+    service: &str,
+    description: &ServiceDescription,
+) -> String {
+    let name = type_name(service);
+    let has_target = description.target.is_some();
+
+    let fields: Vec<GeneratedField> = description
+        .flat_fields()
+        .into_iter()
+        .map(|(key, field)| {
+            let mut field_type = FieldType::from_field(field);
+            // only types that become Python objects as they are can go in lists
+            if let FieldType::List(inner) = &field_type {
+                if matches!(**inner, FieldType::Duration | FieldType::List(_)) {
+                    field_type = FieldType::Arbitrary;
+                }
+            }
+
+            let enum_name = format!("{name}{}", type_name(key));
+            let type_name = rust_type(&field_type, &enum_name);
+
+            GeneratedField {
+                key,
+                identifier: identifier(key),
+                field,
+                field_type,
+                type_name,
+            }
+        })
+        .collect();
+
+    let field_types: Vec<&FieldType> = fields
+        .iter()
+        .map(|field| match &field.field_type {
+            FieldType::List(inner) => &**inner,
+            field_type => field_type,
+        })
+        .collect();
+    let uses = |predicate: fn(&FieldType) -> bool| field_types.iter().any(|ty| predicate(ty));
+
+    let mut out = String::new();
+
+    // region: Imports
+    writeln!(out, "{HEADER}").unwrap();
+    writeln!(out).unwrap();
+
+    let mut std_imports = vec!["str::FromStr"];
+    if uses(|ty| matches!(ty, FieldType::Enum(_))) {
+        std_imports.push("convert::Infallible");
+    }
+    if uses(|ty| matches!(ty, FieldType::Duration)) {
+        std_imports.push("time::Duration");
+    }
+    std_imports.sort();
+    writeln!(out, "use std::{{{}}};", std_imports.join(", ")).unwrap();
+    writeln!(out).unwrap();
+
+    if uses(|ty| matches!(ty, FieldType::Arbitrary)) {
+        writeln!(out, "use arbitrary_value::Arbitrary;").unwrap();
+    }
+
+    let mut pyo3_imports = vec!["prelude::*"];
+    if fields
+        .iter()
+        .any(|field| matches!(field.field_type, FieldType::Number { min, max } if min.is_some() || max.is_some()))
+    {
+        pyo3_imports.push("exceptions::PyValueError");
+    }
+    if uses(|ty| matches!(ty, FieldType::Enum(_))) {
+        pyo3_imports.push("types::{PyDict, PyString}");
+    } else {
+        pyo3_imports.push("types::PyDict");
+    }
+    pyo3_imports.sort();
+    writeln!(out, "use pyo3::{{{}}};", pyo3_imports.join(", ")).unwrap();
+    writeln!(out).unwrap();
+
+    let mut home_assistant_imports = BTreeSet::new();
+    if uses(|ty| matches!(ty, FieldType::EntityId)) {
+        home_assistant_imports.insert("entity_id::EntityId");
+    }
+    let mut registry_imports = Vec::new();
+    if uses(|ty| matches!(ty, FieldType::AreaId)) {
+        registry_imports.push("area::AreaId");
+    }
+    if uses(|ty| matches!(ty, FieldType::DeviceId)) {
+        registry_imports.push("device::DeviceId");
+    }
+    if uses(|ty| matches!(ty, FieldType::FloorId)) {
+        registry_imports.push("floor::FloorId");
+    }
+    if uses(|ty| matches!(ty, FieldType::LabelId)) {
+        registry_imports.push("label::LabelId");
+    }
+    let registry_imports = format!("registry::{{{}}}", registry_imports.join(", "));
+    if registry_imports != "registry::{}" {
+        home_assistant_imports.insert(&registry_imports);
+    }
+    let service_imports = if has_target {
+        "service::{service_domain::ServiceDomain, service_id::ServiceId, service_target::ServiceTarget, supports_response::SupportsResponse, IntoServiceCall}"
+    } else {
+        "service::{service_domain::ServiceDomain, service_id::ServiceId, supports_response::SupportsResponse, IntoServiceCall}"
+    };
+    home_assistant_imports.insert(service_imports);
+    writeln!(
+        out,
+        "use {home_assistant}::{{{}}};",
+        home_assistant_imports
+            .into_iter()
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    // endregion: Imports
+
+    // region: Enums
+    for field in &fields {
+        let variants = match &field.field_type {
+            FieldType::Enum(variants) => variants,
+            FieldType::List(inner) => match &**inner {
+                FieldType::Enum(variants) => variants,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let enum_name = format!("{name}{}", type_name(field.key));
+
+        writeln!(out, "/// The options for `{}`", field.key).unwrap();
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+        writeln!(out, "pub enum {enum_name} {{").unwrap();
+        for (_, variant) in variants {
+            writeln!(out, "    {variant},").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "impl {enum_name} {{").unwrap();
+        writeln!(out, "    pub fn as_str(&self) -> &'static str {{").unwrap();
+        writeln!(out, "        match self {{").unwrap();
+        for (value, variant) in variants {
+            writeln!(out, "            Self::{variant} => {value:?},").unwrap();
+        }
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "impl<'py> IntoPyObject<'py> for {enum_name} {{").unwrap();
+        writeln!(out, "    type Target = PyString;").unwrap();
+        writeln!(out, "    type Output = Bound<'py, Self::Target>;").unwrap();
+        writeln!(out, "    type Error = Infallible;").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {{"
+        )
+        .unwrap();
+        writeln!(out, "        self.as_str().into_pyobject(py)").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+    }
+    // endregion: Enums
+
+    // region: Service call
+    let mut service_doc = String::new();
+    if let Some(service_name) = &description.name {
+        writeln!(service_doc, "{service_name}").unwrap();
+        writeln!(service_doc).unwrap();
+    }
+    if let Some(service_description) = &description.description {
+        writeln!(service_doc, "{service_description}").unwrap();
+        writeln!(service_doc).unwrap();
+    }
+    write!(service_doc, "Calls `{domain}.{service}`").unwrap();
+    doc_comment(&mut out, "", &service_doc);
+
+    let struct_fields = |out: &mut String, visibility: &str, docs: bool| {
+        if has_target {
+            writeln!(out, "    {visibility}target: ServiceTarget,").unwrap();
+        }
+        for field in &fields {
+            if docs {
+                doc_comment(out, "    ", &field_doc(field.field));
+            }
+            if field.field.required {
+                writeln!(
+                    out,
+                    "    {visibility}{}: {},",
+                    field.identifier, field.type_name
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "    {visibility}{}: Option<{}>,",
+                    field.identifier, field.type_name
+                )
+                .unwrap();
+            }
+        }
+    };
+
+    // without a target or required fields there's nothing to construct it from
+    let is_default = !has_target && fields.iter().all(|field| !field.field.required);
+    if is_default {
+        writeln!(out, "#[derive(Debug, Clone, Default)]").unwrap();
+    } else {
+        writeln!(out, "#[derive(Debug, Clone)]").unwrap();
+    }
+    writeln!(out, "pub struct {name} {{").unwrap();
+    struct_fields(&mut out, "pub ", true);
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    let supports_response = match &description.response {
+        None => "None",
+        Some(response) if response.optional => "Optional",
+        Some(_) => "Only",
+    };
+    let mut parameters = Vec::new();
+    if has_target {
+        parameters.push("target: impl Into<ServiceTarget>".to_owned());
+    }
+    for field in fields.iter().filter(|field| field.field.required) {
+        parameters.push(format!("{}: {}", field.identifier, field.type_name));
+    }
+
+    writeln!(out, "impl {name} {{").unwrap();
+    writeln!(
+        out,
+        "    pub const SUPPORTS_RESPONSE: SupportsResponse = SupportsResponse::{supports_response};"
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    pub fn new({}) -> Self {{", parameters.join(", ")).unwrap();
+    writeln!(out, "        Self {{").unwrap();
+    if has_target {
+        writeln!(out, "            target: target.into(),").unwrap();
+    }
+    for field in &fields {
+        if field.field.required {
+            writeln!(out, "            {},", field.identifier).unwrap();
+        } else {
+            writeln!(out, "            {}: None,", field.identifier).unwrap();
+        }
+    }
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "/// Only what was given is sent, since Home Assistant rejects `None` for most fields"
+    )
+    .unwrap();
+    writeln!(out, "#[derive(Debug, Clone)]").unwrap();
+    writeln!(out, "pub struct {name}ServiceData {{").unwrap();
+    struct_fields(&mut out, "", false);
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    let mut destructured: Vec<&str> = Vec::new();
+    if has_target {
+        destructured.push("target");
+    }
+    destructured.extend(fields.iter().map(|field| field.identifier.as_str()));
+    let destructured = destructured.join(", ");
+
+    writeln!(out, "impl<'py> IntoPyObject<'py> for {name}ServiceData {{").unwrap();
+    writeln!(out, "    type Target = PyDict;").unwrap();
+    writeln!(out, "    type Output = Bound<'py, Self::Target>;").unwrap();
+    writeln!(out, "    type Error = PyErr;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {{"
+    )
+    .unwrap();
+    writeln!(out, "        let Self {{ {destructured} }} = self;").unwrap();
+    writeln!(out).unwrap();
+    if has_target {
+        writeln!(out, "        let dict = target.into_pyobject(py)?;").unwrap();
+    } else {
+        writeln!(out, "        let dict = PyDict::new(py);").unwrap();
+    }
+    writeln!(out).unwrap();
+    for field in &fields {
+        if field.field.required {
+            range_check(&mut out, "        ", field);
+            set_item(&mut out, "        ", field);
+        } else {
+            writeln!(out, "        if let Some({0}) = {0} {{", field.identifier).unwrap();
+            range_check(&mut out, "            ", field);
+            set_item(&mut out, "            ", field);
+            writeln!(out, "        }}").unwrap();
+        }
+    }
+    writeln!(out).unwrap();
+    writeln!(out, "        Ok(dict)").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "impl IntoServiceCall for {name} {{").unwrap();
+    writeln!(out, "    type ServiceData = {name}ServiceData;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "    fn into_service_call(self) -> (ServiceDomain, ServiceId, Self::ServiceData) {{"
+    )
+    .unwrap();
+    writeln!(out, "        let service_domain = ServiceDomain::from_str({domain:?}).expect(\"statically written and known to be a valid slug; hoping to get compiler checks instead in the future\");").unwrap();
+    writeln!(out, "        let service_id = ServiceId::from_str({service:?}).expect(\"statically written and known to be a valid slug; hoping to get compiler checks instead in the future\");").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "        let Self {{ {destructured} }} = self;").unwrap();
+    writeln!(
+        out,
+        "        let service_data = {name}ServiceData {{ {destructured} }};"
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "        (service_domain, service_id, service_data)").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    // endregion: Service call
+
+    if uses(|ty| matches!(ty, FieldType::Duration)) {
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "/// Home Assistant takes durations as a dictionary, whether or not it also takes them as seconds"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "fn time_period_dict(py: Python<'_>, duration: Duration) -> PyResult<Bound<'_, PyDict>> {{"
+        )
+        .unwrap();
+        writeln!(out, "    let dict = PyDict::new(py);").unwrap();
+        writeln!(
+            out,
+            "    dict.set_item(\"seconds\", duration.as_secs_f64())?;"
+        )
+        .unwrap();
+        writeln!(out, "    Ok(dict)").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    out
+}
@@ -0,0 +1,194 @@
+//! Generates typed service calls like the hand-written `home_assistant::light::service::turn_on::TurnOn`
+//! for every service Home Assistant describes, so that new integrations can be used without writing bindings.
+//!
+//! It reads the descriptions as JSON, which can be dumped from Home Assistant's Python with:
+//!
+//! ```python
+//! import json
+//! from homeassistant.helpers.service import async_get_all_descriptions
+//!
+//! with open("services.json", "w") as file:
+//!     json.dump(await async_get_all_descriptions(hass), file, default=list)
+//! ```
+//!
+//! Then e.g. `service-catalog-codegen services.json home-assistant/src/service/catalog` writes one module per
+//! domain with one module per service, to be declared with `pub mod catalog;` and formatted with `cargo fmt`.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+use description::Descriptions;
+use generate::{identifier, parent_module, service_module};
+use snafu::{ResultExt, Snafu};
+
+mod description;
+mod generate;
+mod selector;
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// The JSON dump of `async_get_all_descriptions`
+    services: PathBuf,
+    /// Where to write the catalog, which is overwritten service by service
+    out_dir: PathBuf,
+    /// How the generated code refers to the `home-assistant` crate, which is `crate` when generating into it
+    #[arg(long, default_value = "crate")]
+    home_assistant: String,
+    /// Only generate these domains, instead of every domain in the dump
+    #[arg(long)]
+    domain: Vec<String>,
+}
+
+#[derive(Debug, Snafu)]
+enum Error {
+    #[snafu(display("could not read the service descriptions from {}", path.display()))]
+    ReadDescriptions { source: io::Error, path: PathBuf },
+
+    #[snafu(display("could not parse the service descriptions"))]
+    ParseDescriptions { source: serde_json::Error },
+
+    #[snafu(display("could not write {}", path.display()))]
+    WriteModule { source: io::Error, path: PathBuf },
+}
+
+fn write_module(path: PathBuf, contents: String) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context(WriteModuleSnafu { path: parent })?;
+    }
+
+    fs::write(&path, contents).context(WriteModuleSnafu { path })
+}
+
+fn read_descriptions(path: &Path) -> Result<Descriptions, Error> {
+    let descriptions = fs::read_to_string(path).context(ReadDescriptionsSnafu {
+        path: path.to_owned(),
+    })?;
+
+    serde_json::from_str(&descriptions).context(ParseDescriptionsSnafu)
+}
+
+/// Every domain in `only_domains`, or every domain there is if it's empty
+fn generate(
+    descriptions: &Descriptions,
+    out_dir: &Path,
+    home_assistant: &str,
+    only_domains: &[String],
+) -> Result<(), Error> {
+    let domains: Vec<&str> = descriptions
+        .keys()
+        .map(String::as_str)
+        .filter(|domain| only_domains.is_empty() || only_domains.iter().any(|only| only == domain))
+        .collect();
+
+    for &domain in &domains {
+        let domain_services = &descriptions[domain];
+        let domain_dir = out_dir.join(identifier(domain));
+
+        let service_names: Vec<&str> = domain_services.keys().map(String::as_str).collect();
+        write_module(
+            domain_dir.join("mod.rs"),
+            parent_module(
+                &format!("Services of the `{domain}` domain"),
+                &service_names,
+            ),
+        )?;
+
+        for (service, description) in domain_services {
+            write_module(
+                domain_dir.join(format!("{}.rs", identifier(service))),
+                service_module(home_assistant, domain, service, description),
+            )?;
+        }
+    }
+
+    write_module(
+        out_dir.join("mod.rs"),
+        parent_module(
+            "Typed calls for the services Home Assistant describes",
+            &domains,
+        ),
+    )
+}
+
+#[snafu::report]
+fn main() -> Result<(), Error> {
+    let Args {
+        services,
+        out_dir,
+        home_assistant,
+        domain: only_domains,
+    } = Args::parse();
+
+    let descriptions = read_descriptions(&services)?;
+
+    generate(&descriptions, &out_dir, &home_assistant, &only_domains)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process::{self, Command},
+    };
+
+    use super::{generate, read_descriptions};
+
+    fn files(dir: &Path) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files.extend(files_under(dir, &path));
+            } else {
+                files.push(path.strip_prefix(dir).unwrap().to_owned());
+            }
+        }
+
+        files.sort();
+        files
+    }
+
+    fn files_under(root: &Path, dir: &Path) -> Vec<PathBuf> {
+        files(dir)
+            .into_iter()
+            .map(|file| dir.strip_prefix(root).unwrap().join(file))
+            .collect()
+    }
+
+    /// The catalog generated from `fixtures/services.json` is checked in to `home-assistant`,
+    /// which compiles it in its tests, so this makes sure the generator still writes code that compiles
+    #[test]
+    fn generates_the_catalog_that_home_assistant_compiles() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let descriptions = read_descriptions(&manifest_dir.join("fixtures/services.json")).unwrap();
+        let checked_in = manifest_dir.join("../home-assistant/src/service/fixture_catalog");
+
+        let out_dir = env::temp_dir().join(format!("service-catalog-codegen-{}", process::id()));
+        let _ = fs::remove_dir_all(&out_dir);
+        generate(&descriptions, &out_dir, "crate", &[]).unwrap();
+
+        let formatted = Command::new("rustfmt")
+            .args(["--edition", "2021"])
+            .arg(out_dir.join("mod.rs"))
+            .status()
+            .unwrap();
+        assert!(formatted.success());
+
+        assert_eq!(files(&out_dir), files(&checked_in));
+        for file in files(&out_dir) {
+            assert_eq!(
+                fs::read_to_string(out_dir.join(&file)).unwrap(),
+                fs::read_to_string(checked_in.join(&file)).unwrap(),
+                "{} is out of date; regenerate the catalog with `service-catalog-codegen service-catalog-codegen/fixtures/services.json home-assistant/src/service/fixture_catalog` and `cargo fmt`",
+                file.display(),
+            );
+        }
+
+        fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
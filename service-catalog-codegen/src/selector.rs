@@ -0,0 +1,119 @@
+use heck::ToUpperCamelCase;
+use serde_json::Value;
+
+use super::description::Field;
+
+/// The Rust type a field is generated as, going by its selector
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Bool,
+    /// Home Assistant coerces numbers to integers where it needs them, so every number is a float
+    Number {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    String,
+    /// A `select` without custom values, as `(value, variant)` pairs
+    Enum(Vec<(String, String)>),
+    EntityId,
+    DeviceId,
+    AreaId,
+    FloorId,
+    LabelId,
+    Rgb,
+    Duration,
+    /// Anything without a better type, like `object`
+    Arbitrary,
+    List(Box<FieldType>),
+}
+
+impl FieldType {
+    pub fn from_field(field: &Field) -> Self {
+        let Some((selector, config)) = field
+            .selector
+            .as_ref()
+            .and_then(|selector| selector.iter().next())
+        else {
+            return FieldType::Arbitrary;
+        };
+        let config = config.as_ref();
+
+        let field_type = match selector.as_str() {
+            "boolean" => FieldType::Bool,
+            "number" | "color_temp" => FieldType::Number {
+                min: number(config, "min"),
+                max: number(config, "max"),
+            },
+            "text" | "template" | "icon" | "theme" | "time" | "date" | "datetime" => {
+                FieldType::String
+            }
+            "select" => select(config),
+            "entity" => FieldType::EntityId,
+            "device" => FieldType::DeviceId,
+            "area" => FieldType::AreaId,
+            "floor" => FieldType::FloorId,
+            "label" => FieldType::LabelId,
+            "color_rgb" => FieldType::Rgb,
+            "duration" => FieldType::Duration,
+            _ => FieldType::Arbitrary,
+        };
+
+        let multiple = config
+            .and_then(|config| config.get("multiple"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        if multiple {
+            FieldType::List(Box::new(field_type))
+        } else {
+            field_type
+        }
+    }
+}
+
+fn number(config: Option<&Value>, key: &str) -> Option<f64> {
+    config?.get(key)?.as_f64()
+}
+
+fn select(config: Option<&Value>) -> FieldType {
+    let custom_value = config
+        .and_then(|config| config.get("custom_value"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if custom_value {
+        return FieldType::String;
+    }
+
+    let options = config
+        .and_then(|config| config.get("options"))
+        .and_then(Value::as_array);
+    let Some(options) = options else {
+        return FieldType::String;
+    };
+
+    let values = options.iter().filter_map(|option| match option {
+        Value::String(value) => Some(value.clone()),
+        Value::Object(option) => option.get("value")?.as_str().map(str::to_owned),
+        _ => None,
+    });
+
+    let mut variants: Vec<(String, String)> = Vec::new();
+    for value in values {
+        let mut variant = value.to_upper_camel_case();
+        // `Self` can't be a variant, not even as a raw identifier
+        if !variant.starts_with(|c: char| c.is_ascii_alphabetic()) || variant == "Self" {
+            variant.insert_str(0, "Option");
+        }
+        if variants.iter().any(|(_, existing)| *existing == variant) {
+            variant.push_str(&variants.len().to_string());
+        }
+
+        variants.push((value, variant));
+    }
+
+    if variants.is_empty() {
+        FieldType::String
+    } else {
+        FieldType::Enum(variants)
+    }
+}